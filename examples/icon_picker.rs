@@ -0,0 +1,69 @@
+use iced::{text_input, Align, Column, Container, Element, Length, Sandbox, Settings, Text, TextInput};
+use iced_custom_widget as icw;
+use icw::components::grid::{VirtualGrid, VirtualState};
+use icw::components::{Icon, Icons};
+
+pub struct IconPicker {
+    search: String,
+    search_state: text_input::State,
+    grid_state: VirtualState,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SearchChanged(String),
+    SearchSubmitted,
+}
+
+fn main() -> iced::Result {
+    IconPicker::run(Settings::default())
+}
+
+impl Sandbox for IconPicker {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            search: String::new(),
+            search_state: text_input::State::focused(),
+            grid_state: VirtualState::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Icon Picker")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::SearchChanged(search) => self.search = search,
+            Message::SearchSubmitted => {
+                let search = self.search.to_lowercase();
+                if let Some(index) = Icons::ALL.iter().position(|icon| format!("{:?}", icon).to_lowercase().contains(&search)) {
+                    self.grid_state.scroll_to(index);
+                }
+            }
+        }
+    }
+
+    fn view(&mut self) -> Element<'_, Self::Message> {
+        let search = TextInput::new(&mut self.search_state, "Jump to an icon, then press enter", &self.search, Message::SearchChanged)
+            .on_submit(Message::SearchSubmitted)
+            .padding(10);
+        // only the icons in view are built, out of the hundreds there are
+        let grid = VirtualGrid::new(&mut self.grid_state, Icons::ALL.len(), |index| {
+            let icon = Icons::ALL[index];
+            Column::new()
+                .spacing(5)
+                .align_items(Align::Center)
+                .push(Icon::new(icon).size(32))
+                .push(Text::new(format!("{:?}", icon)).size(12))
+                .into()
+        })
+        .cell_size(120, 72)
+        .padding(10);
+
+        let content = Column::new().spacing(10).push(search).push(grid);
+        Container::new(content).padding(20).width(Length::Fill).height(Length::Fill).into()
+    }
+}
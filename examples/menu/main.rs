@@ -1,6 +1,6 @@
 mod app;
 use app::{App, AppMessage};
-use cw::components::{Grid, IconBrands};
+use cw::components::{grid::{self, Columns}, Grid, IconBrands};
 use cw::styles::custom_styles::CustomTextInput;
 use cw::utils::Theme;
 use iced::{
@@ -14,6 +14,7 @@ pub struct Menu {
     applications: Vec<App>,
     filtered_application: Vec<App>,
     scroll: scrollable::State,
+    grid_state: grid::State,
 }
 
 impl Menu {
@@ -35,6 +36,7 @@ pub enum MenuMessage {
     SearchChanged(String),
     ActionSearch,
     AppMessage(usize, AppMessage),
+    AppsReordered(usize, usize),
 }
 
 impl Application for Menu {
@@ -79,6 +81,7 @@ impl Application for Menu {
                 applications: applications.clone(),
                 filtered_application: applications.clone(),
                 scroll: scrollable::State::new(),
+                grid_state: grid::State::new(),
             },
             Command::none(),
         )
@@ -92,6 +95,9 @@ impl Application for Menu {
         match message {
             MenuMessage::SearchChanged(text) => {
                 self.search_text = text;
+                // the items move once filtered
+                self.grid_state.clear_selection();
+                self.grid_state.set_highlighted(None);
                 self.filtered_application = self
                     .applications
                     .iter()
@@ -115,6 +121,16 @@ impl Application for Menu {
                     .cloned()
                     .collect();
             }
+            MenuMessage::AppsReordered(from, to) => {
+                let app = self.filtered_application.remove(from);
+                self.filtered_application.insert(to, app);
+                // the order is kept while all applications are shown
+                if self.search_text.is_empty() {
+                    self.applications = self.filtered_application.clone();
+                }
+                self.grid_state.set_selection(Some(to));
+                self.grid_state.set_highlighted(Some(to));
+            }
             MenuMessage::AppMessage(i, app_message) => {
                 if let Some(app) = self.applications.get_mut(i) {
                     app.update(app_message);
@@ -150,12 +166,22 @@ impl Application for Menu {
             .filtered_application
             .iter_mut()
             .enumerate()
-            .fold(Grid::new().column_width(175), |grid, (i, app)| {
-                grid.push(
-                    app.view()
-                        .map(move |message| MenuMessage::AppMessage(i, message)),
-                )
-            })
+            .fold(
+                Grid::new()
+                    .auto_fill(150, 200)
+                    .align_x(Align::Center)
+                    .breakpoint(600, Columns::Fixed(2))
+                    .breakpoint(1000, Columns::Fixed(4))
+                    .selectable(&mut self.grid_state)
+                    .on_activate(|i| MenuMessage::AppMessage(i, AppMessage::AppClicked))
+                    .on_reorder(MenuMessage::AppsReordered),
+                |grid, (i, app)| {
+                    grid.push(
+                        app.view()
+                            .map(move |message| MenuMessage::AppMessage(i, message)),
+                    )
+                },
+            )
             .into();
 
         let content = Column::new()
@@ -1,6 +1,8 @@
-use iced::{scrollable, Container, Element, Length, Sandbox, Scrollable, Settings};
+use iced::{button, text_input, Align, Button, Checkbox, Color, Column, Container, Element, HorizontalAlignment, Length, Row, Sandbox, Settings, Text, TextInput};
 use iced_custom_widget as icw;
-use icw::components::table::{self, Table, TableData, TableError, TableOptions, TableResult};
+use icw::components::table::{self, Aggregate, CellKind, ColumnLayout, ExportFormat, MenuItem, SelectionMode, Table, TableData, TableError, TableOptions, TableOrder, TableResult, TextOverflow};
+use icw::components::Icons;
+use icw::styles::table_row;
 use icw::table_columns;
 use rand::Rng;
 use serde::{Deserialize, Serialize};
@@ -39,11 +41,39 @@ impl TableData for Task {
         };
         Ok(value.unwrap())
     }
+
+    fn set_field_value(&mut self, field_name: &str, value: Value) -> TableResult<()> {
+        let invalid_type = |expected: &str| TableError::InvalidFieldType(field_name.to_owned(), expected.to_owned());
+        match field_name {
+            "description" => {
+                let description = value.as_str().ok_or_else(|| invalid_type("a string"))?;
+                if description.trim().is_empty() {
+                    return Err(TableError::InvalidFieldValue(field_name.to_owned(), String::from("it cannot be empty")));
+                }
+                self.description = description.to_owned();
+            }
+            "responsible_user" => {
+                let user = value.as_str().ok_or_else(|| invalid_type("a string"))?;
+                self.responsible_user = if user.is_empty() { None } else { Some(user.to_owned()) };
+            }
+            "progress" => {
+                let progress = value.as_i64().ok_or_else(|| invalid_type("a number"))?;
+                if !(0..=100).contains(&progress) {
+                    return Err(TableError::InvalidFieldValue(field_name.to_owned(), String::from("it must be between 0 and 100")));
+                }
+                self.progress = progress as u8;
+            }
+            "is_favorite" => self.is_favorite = value.as_bool().ok_or_else(|| invalid_type("a boolean"))?,
+            s => return Err(TableError::ReadOnlyField(s.to_owned())),
+        }
+        Ok(())
+    }
 }
 
-fn create_mock_tasks() -> Vec<Task> {
+fn create_mock_tasks(count: usize) -> Vec<Task> {
+    let users = [Some("Dara"), Some("Sokha"), Some("Vanna"), None];
     let mut rng = rand::thread_rng();
-    (0..27)
+    (0..count)
         .map(|i| Task {
             id: format!("task-{}", i + 1),
             description: String::from("These are not the Lorem Ipsums you are looking for"),
@@ -51,15 +81,57 @@ fn create_mock_tasks() -> Vec<Task> {
             is_favorite: rng.gen(),
             is_archived: rng.gen(),
             is_locked: rng.gen(),
+            responsible_user: users[rng.gen_range(0..users.len())].map(String::from),
             ..Task::default()
         })
         .collect()
 }
 
+fn columns() -> Vec<table::TableColumn> {
+    let mut columns = table_columns![
+        ("id", "ID"),
+        ("description", "Description"),
+        ("progress", "Progress"),
+        ("responsible_user", "User"),
+        ("is_locked", "Locked"),
+        ("is_favorite", "Favorite"),
+        ("is_archived", "Archived"),
+    ];
+    columns[0] = columns[0].clone().align(HorizontalAlignment::Right).min_width(60.0);
+    columns[1] = columns[1].clone().editable(true).width(Length::Fill).min_width(200.0).overflow(TextOverflow::Ellipsis);
+    columns[2] = columns[2].clone().kind(CellKind::ProgressBar).editable(true);
+    columns[3] = columns[3].clone().editable(true).max_width(160.0);
+    columns[4] = columns[4].clone().kind(CellKind::icon(Icons::Lock, Some(Icons::Unlock))).align(HorizontalAlignment::Center).tooltip("Locked tasks cannot be reassigned");
+    columns[5] = columns[5].clone().kind(CellKind::Checkbox).editable(true).short_name("Fav").max_width(48.0);
+    columns[6] = columns[6].clone().kind(CellKind::Checkbox);
+    columns
+}
+
 pub struct TableDemo {
-    scrollable_state: scrollable::State,
     tasks: Vec<Task>,
     table_state: table::State,
+    search_state: text_input::State,
+    export_states: [button::State; 3],
+    query: String,
+    status: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    TaskSelected(usize),
+    SelectionChanged(Vec<usize>),
+    TaskOpened(usize),
+    TasksSorted(String, TableOrder),
+    TaskEdited(usize, String),
+    SearchChanged(String),
+    ColumnsChanged(ColumnLayout),
+    PageChanged(usize),
+    ArchivedShown(bool),
+    GroupedByUser(bool),
+    RowsCopied(String),
+    FavoriteToggled(String),
+    ArchiveToggled(String),
+    Export(ExportFormat),
 }
 
 
@@ -71,14 +143,19 @@ fn main() -> iced::Result {
 }
 
 impl Sandbox for TableDemo {
-    type Message = ();
+    type Message = Message;
 
     fn new() -> Self {
-        let tasks = create_mock_tasks();
+        let tasks = create_mock_tasks(10_000);
+        let mut table_state = table::State::new();
+        table_state.set_aggregate("progress", Aggregate::Average);
         Self {
-            scrollable_state: Default::default(),
             tasks,
-            table_state: Default::default(),
+            table_state,
+            search_state: Default::default(),
+            export_states: Default::default(),
+            query: String::new(),
+            status: String::from("No task selected"),
         }
     }
 
@@ -86,34 +163,150 @@ impl Sandbox for TableDemo {
         String::from("Table Demo")
     }
 
-    fn update(&mut self, _message: Self::Message) {
-        todo!()
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::TaskSelected(idx) => self.status = format!("Selected {}", self.tasks[idx].id),
+            Message::SelectionChanged(rows) if rows.len() > 1 => self.status = format!("Selected {} tasks", rows.len()),
+            Message::SelectionChanged(_) => {}
+            Message::TaskOpened(idx) => self.status = format!("Opened {}", self.tasks[idx].id),
+            Message::TaskEdited(idx, column) => self.status = format!("Edited {} of {}", column, self.tasks[idx].id),
+            Message::SearchChanged(query) => {
+                self.table_state.set_query(query.clone());
+                self.query = query;
+            }
+            Message::ColumnsChanged(layout) => self.status = format!("Columns {}", serde_json::to_string(&layout).unwrap()),
+            Message::PageChanged(page) => self.status = format!("Page {}", page + 1),
+            Message::RowsCopied(text) => self.status = format!("Copied {} rows", text.lines().count()),
+            Message::FavoriteToggled(id) => {
+                if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
+                    task.is_favorite = !task.is_favorite;
+                }
+            }
+            Message::ArchiveToggled(id) => {
+                if let Some(task) = self.tasks.iter_mut().find(|task| task.id == id) {
+                    task.is_archived = !task.is_archived;
+                }
+            }
+            Message::Export(format) => {
+                let extension = match format {
+                    ExportFormat::Csv => "csv",
+                    ExportFormat::Tsv => "tsv",
+                    ExportFormat::Json => "json",
+                    ExportFormat::Yaml => "yaml",
+                };
+                let path = std::env::temp_dir().join(format!("tasks.{}", extension));
+                self.status = match self.table_state.export(&self.tasks, columns(), format).map(|text| std::fs::write(&path, text)) {
+                    Ok(Ok(())) => format!("Exported to {}", path.display()),
+                    Ok(Err(error)) => error.to_string(),
+                    Err(error) => error.to_string(),
+                };
+            }
+            Message::GroupedByUser(is_grouped) => self.table_state.set_group_by(if is_grouped { Some(String::from("responsible_user")) } else { None }),
+            Message::ArchivedShown(is_shown) => self.table_state.set_column_hidden("is_archived", !is_shown),
+            Message::TasksSorted(column, order) => {
+                self.status = match order {
+                    TableOrder::Unordered => String::from("Sorting cleared"),
+                    TableOrder::Ascending => format!("Sorted by {} ascending", column),
+                    TableOrder::Descending => format!("Sorted by {} descending", column),
+                }
+            }
+        }
     }
 
     fn view(&mut self) -> Element<'_, Self::Message> {
-        let columns = table_columns![
-            ("id", "ID"),
-            ("description", "Description"),
-            ("progress", "Progress"),
-            ("responsible_user", "User"),
-            ("is_locked", "Locked"),
-            ("is_favorite", "Favorite"),
-            ("is_archived", "Archived"),
-        ];
         let option = TableOptions { orderable: true };
-        let table = Table::new(&mut self.table_state, columns, &mut self.tasks)
-            // .width(Length::Fill)
+        let task_count = self.tasks.len();
+        let group_by_user = Checkbox::new(self.table_state.group_by().is_some(), "Group by user", Message::GroupedByUser);
+        let show_archived = Checkbox::new(!self.table_state.is_column_hidden("is_archived"), "Archived column", Message::ArchivedShown);
+        let [csv_state, json_state, yaml_state] = &mut self.export_states;
+        let export = Row::new()
+            .spacing(5)
+            .push(Button::new(csv_state, Text::new("CSV")).on_press(Message::Export(ExportFormat::Csv)))
+            .push(Button::new(json_state, Text::new("JSON")).on_press(Message::Export(ExportFormat::Json)))
+            .push(Button::new(yaml_state, Text::new("YAML")).on_press(Message::Export(ExportFormat::Yaml)));
+        let table = Table::new(&mut self.table_state, columns(), &mut self.tasks)
+            .width(Length::Fill)
             // .column_max_width(227.0)
-            .option(option);
-        Scrollable::new(&mut self.scrollable_state)
-            .push(
-                Container::new(table)
-                    .padding(20)
-                    .width(Length::Fill)
-                    .center_x()
-                    .center_y(),
-            )
-            //  .style(CustomScrollable::Default(Theme::light().palette))
+            .height(Length::Fill)
+            .virtualized(true)
+            .pinned_columns(1)
+            .header_filters(true)
+            .totals_row(true)
+            .paginated(100)
+            .page_sizes(vec![50, 100, 500])
+            .on_page_change(Message::PageChanged)
+            .option(option)
+            .style(style::StripedTable)
+            .row_style(|task| {
+                if task.is_archived {
+                    Some(table_row::Style {
+                        text_color: Color::from_rgb(0.6, 0.6, 0.6),
+                        ..table_row::Style::default()
+                    })
+                } else {
+                    None
+                }
+            })
+            .cell_view("responsible_user", |value| match value.as_str() {
+                Some(user) => Text::new(user).into(),
+                None => Text::new("Unassigned").color([0.6, 0.6, 0.6]).into(),
+            })
+            .selection_mode(SelectionMode::Multiple)
+            .on_select(|idx, _| Message::TaskSelected(idx))
+            .on_selection_change(Message::SelectionChanged)
+            .on_activate(|idx, _| Message::TaskOpened(idx))
+            .on_edit(|idx, column, _| Message::TaskEdited(idx, column.to_string()))
+            .on_sort(|column, order| Message::TasksSorted(column.to_string(), order))
+            .on_column_layout_change(Message::ColumnsChanged)
+            .on_copy(Message::RowsCopied)
+            .header_context_menu(true)
+            .row_context_menu(|task| {
+                let favorite = if task.is_favorite { "Remove from favorites" } else { "Add to favorites" };
+                let archive = if task.is_archived { "Restore" } else { "Archive" };
+                vec![
+                    MenuItem::new(favorite, Message::FavoriteToggled(task.id.clone())),
+                    // locked tasks cannot be archived
+                    if task.is_locked { MenuItem::disabled(archive) } else { MenuItem::new(archive, Message::ArchiveToggled(task.id.clone())) },
+                ]
+            });
+        let search = TextInput::new(&mut self.search_state, "Search tasks", &self.query, Message::SearchChanged).padding(5).width(Length::Units(250));
+        let toolbar = Row::new()
+            .spacing(10)
+            .align_items(Align::Center)
+            .push(search)
+            .push(show_archived)
+            .push(group_by_user)
+            .push(export)
+            .push(Text::new(format!("{} of {} tasks", table.row_count(), task_count)))
+            .push(Text::new(&self.status));
+        let content = Column::new()
+            .spacing(10)
+            .height(Length::Fill)
+            .push(toolbar)
+            .push(table);
+        Container::new(content)
+            .padding(20)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .center_x()
             .into()
     }
 }
+
+mod style {
+    use iced::{Background, Color};
+    use iced_custom_widget::styles::table::{Style, StyleSheet};
+
+    pub struct StripedTable;
+
+    impl StyleSheet for StripedTable {
+        fn active(&self) -> Style {
+            Style {
+                striped_background: Some(Background::Color(Color::from_rgb8(245, 245, 245))),
+                row_divider: Some(Color::from_rgb8(230, 230, 230)),
+                column_divider: Some(Color::from_rgb8(230, 230, 230)),
+                ..Style::default()
+            }
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use iced::{Container, Element, Length, Sandbox, Settings};
+use iced_custom_widget as icw;
+use icw::components::table::{self, Table, TableData};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, TableData)]
+pub struct Contact {
+    #[table(order = 0, editable)]
+    pub name: String,
+    #[table(label = "E-mail", editable)]
+    pub email: String,
+    #[serde(rename = "phone")]
+    pub phone_number: Option<String>,
+    #[table(label = "Age", order = 1)]
+    pub age: u8,
+    #[table(skip)]
+    pub notes: String,
+}
+
+pub struct TableDerive {
+    contacts: Vec<Contact>,
+    table_state: table::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {}
+
+fn main() -> iced::Result {
+    TableDerive::run(Settings::default())
+}
+
+impl Sandbox for TableDerive {
+    type Message = Message;
+
+    fn new() -> Self {
+        let contact = |name: &str, email: &str, age| Contact {
+            name: name.to_string(),
+            email: email.to_string(),
+            age,
+            ..Contact::default()
+        };
+        Self {
+            contacts: vec![
+                contact("Dara", "dara@example.com", 31),
+                contact("Sophea", "sophea@example.com", 27),
+                contact("Vuthy", "vuthy@example.com", 45),
+            ],
+            table_state: table::State::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Table Derive")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {}
+    }
+
+    fn view(&mut self) -> Element<'_, Self::Message> {
+        let table = Table::new(&mut self.table_state, Contact::columns(), &mut self.contacts).width(Length::Fill);
+        Container::new(table).padding(20).width(Length::Fill).height(Length::Fill).into()
+    }
+}
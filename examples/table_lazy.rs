@@ -0,0 +1,143 @@
+use iced::{executor, text_input, Application, Column, Command, Container, Element, Length, Settings, Text, TextInput};
+use iced_custom_widget as icw;
+use icw::components::table::{self, LazySource, Query, Table, TableData, TableError, TableOrder, TableResult, TableSource, TextOverflow};
+use icw::table_columns;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::ops::Range;
+use std::time::Duration;
+
+const LOG_COUNT: usize = 1_000_000;
+const BLOCK_SIZE: usize = 100;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub line: usize,
+    pub level: String,
+    pub message: String,
+}
+
+impl TableData for LogEntry {
+    fn get_field_value(&self, field_name: &str) -> TableResult<Value> {
+        let value = match field_name {
+            "line" => serde_json::to_value(self.line),
+            "level" => serde_json::to_value(&self.level),
+            "message" => serde_json::to_value(&self.message),
+            s => return Err(TableError::InvalidFieldName(s.to_owned())),
+        };
+        Ok(value.unwrap())
+    }
+}
+
+/// Stands for a large file-backed store, entries are made up from their line number.
+fn log_entry(line: usize) -> LogEntry {
+    let levels = ["INFO", "INFO", "INFO", "WARN", "ERROR"];
+    LogEntry {
+        line: line + 1,
+        level: levels[line * 7 % levels.len()].to_string(),
+        message: format!("request {} served in {} ms", line * 31 % 9973, line * 17 % 250),
+    }
+}
+
+/// Loads the entries of `range` among those matching `query`, with the number of matching entries.
+async fn load(range: Range<usize>, query: Query) -> (Range<usize>, Query, usize, Vec<LogEntry>) {
+    std::thread::sleep(Duration::from_millis(300));
+    let text = query.text.trim().to_lowercase();
+    let mut matching: Vec<usize> = if text.is_empty() {
+        (0..LOG_COUNT).collect()
+    } else {
+        (0..LOG_COUNT).filter(|&line| log_entry(line).level.to_lowercase().contains(&text)).collect()
+    };
+    // the store keeps its entries by line, the only order it can sort by
+    if query.sort_keys.iter().any(|key| key.column == "line" && key.order == TableOrder::Descending) {
+        matching.reverse();
+    }
+    let entries = matching.iter().skip(range.start).take(range.len()).map(|&line| log_entry(line)).collect();
+    (range, query, matching.len(), entries)
+}
+
+pub struct LogViewer {
+    logs: LazySource<LogEntry>,
+    table_state: table::State,
+    search_state: text_input::State,
+    query: String,
+    /// Why some loaded entries were not shown.
+    notice: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    Fetch(Range<usize>),
+    Loaded((Range<usize>, Query, usize, Vec<LogEntry>)),
+    SearchChanged(String),
+}
+
+fn main() -> iced::Result {
+    LogViewer::run(Settings {
+        default_text_size: 13,
+        ..Settings::default()
+    })
+}
+
+impl Application for LogViewer {
+    type Executor = executor::Default;
+    type Message = Message;
+    type Flags = ();
+
+    fn new(_flags: ()) -> (Self, Command<Message>) {
+        // the table asks for the rows it shows on its first event, the first ones are loaded right away
+        let mut logs = LazySource::new(LOG_COUNT, BLOCK_SIZE);
+        let first_blocks = logs.fetch(0..BLOCK_SIZE);
+        let commands = first_blocks.into_iter().map(|range| Command::perform(load(range, Query::default()), Message::Loaded));
+        let viewer = Self {
+            logs,
+            table_state: table::State::new(),
+            search_state: text_input::State::new(),
+            query: String::new(),
+            notice: None,
+        };
+        (viewer, Command::batch(commands))
+    }
+
+    fn title(&self) -> String {
+        String::from("Log Viewer")
+    }
+
+    fn update(&mut self, message: Message) -> Command<Message> {
+        match message {
+            Message::Fetch(range) => return Command::perform(load(range, self.logs.query().clone()), Message::Loaded),
+            // entries loaded for a previous query are dropped
+            Message::Loaded((range, query, count, entries)) if query == *self.logs.query() => {
+                self.logs.set_len(count);
+                // the table fetches again the blocks left incomplete
+                if !self.logs.insert(range.start, entries) {
+                    self.notice = Some(format!("Entries loaded from {} past the {} matching were dropped", range.start + 1, count));
+                }
+            }
+            Message::Loaded(_) => {}
+            Message::SearchChanged(query) => {
+                self.notice = None;
+                self.table_state.set_query(query.clone());
+                self.query = query;
+            }
+        }
+        Command::none()
+    }
+
+    fn view(&mut self) -> Element<'_, Message> {
+        let mut columns = table_columns![("line", "Line"), ("level", "Level"), ("message", "Message")];
+        columns[2] = columns[2].clone().width(Length::Fill).overflow(TextOverflow::Ellipsis);
+        let search = TextInput::new(&mut self.search_state, "Filter by level", &self.query, Message::SearchChanged).padding(5).width(Length::Units(250));
+        let count = match &self.notice {
+            Some(notice) => Text::new(format!("{} entries. {}", self.logs.len(), notice)),
+            None => Text::new(format!("{} entries", self.logs.len())),
+        };
+        let table = Table::new(&mut self.table_state, columns, &mut self.logs)
+            .width(Length::Fill)
+            .height(Length::Fill)
+            .virtualized(true)
+            .on_fetch(Message::Fetch);
+        let content = Column::new().spacing(10).push(search).push(count).push(table);
+        Container::new(content).padding(20).width(Length::Fill).height(Length::Fill).into()
+    }
+}
@@ -0,0 +1,123 @@
+use iced::{Container, Element, Length, Sandbox, Settings};
+use iced_custom_widget as icw;
+use icw::components::table::{self, Table, TableData, TableError, TableResult, TreeData};
+use icw::table_columns;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct FileEntry {
+    pub path: String,
+    pub name: String,
+    pub size: Option<u64>,
+    pub is_dir: bool,
+    pub is_loaded: bool,
+}
+
+impl FileEntry {
+    fn dir(parent: &str, name: &str) -> Self {
+        Self {
+            path: format!("{}/{}", parent, name),
+            name: name.to_string(),
+            is_dir: true,
+            ..Self::default()
+        }
+    }
+
+    fn file(parent: &str, name: &str, size: u64) -> Self {
+        Self {
+            path: format!("{}/{}", parent, name),
+            name: name.to_string(),
+            size: Some(size),
+            ..Self::default()
+        }
+    }
+}
+
+impl TableData for FileEntry {
+    fn get_field_value(&self, field_name: &str) -> TableResult<Value> {
+        let value = match field_name {
+            "name" => serde_json::to_value(&self.name),
+            "size" => serde_json::to_value(&self.size),
+            "kind" => serde_json::to_value(if self.is_dir { "Folder" } else { "File" }),
+            s => return Err(TableError::InvalidFieldName(s.to_owned())),
+        };
+        Ok(value.unwrap())
+    }
+}
+
+impl TreeData for FileEntry {
+    fn key(&self) -> String {
+        self.path.clone()
+    }
+
+    fn parent_key(&self) -> Option<String> {
+        self.path.rfind('/').filter(|&idx| idx > 0).map(|idx| self.path[..idx].to_string())
+    }
+
+    fn has_children(&self) -> bool {
+        self.is_dir && !self.is_loaded
+    }
+}
+
+/// Stands for reading a directory, which the tree only does once it is expanded.
+fn read_dir(dir: &FileEntry) -> Vec<FileEntry> {
+    let depth = dir.path.matches('/').count();
+    let mut entries: Vec<FileEntry> = (1..=3).map(|i| FileEntry::file(&dir.path, &format!("file-{}.txt", i), (depth * 1000 + i * 128) as u64)).collect();
+    if depth < 4 {
+        entries.push(FileEntry::dir(&dir.path, "src"));
+        entries.push(FileEntry::dir(&dir.path, "docs"));
+    }
+    entries
+}
+
+pub struct TableTree {
+    files: Vec<FileEntry>,
+    table_state: table::State,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    DirExpanded(usize),
+}
+
+fn main() -> iced::Result {
+    TableTree::run(Settings::default())
+}
+
+impl Sandbox for TableTree {
+    type Message = Message;
+
+    fn new() -> Self {
+        Self {
+            files: vec![FileEntry::dir("", "home"), FileEntry::dir("", "etc")],
+            table_state: table::State::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        String::from("Table Tree")
+    }
+
+    fn update(&mut self, message: Self::Message) {
+        match message {
+            Message::DirExpanded(row) => {
+                if let Some(dir) = self.files.get_mut(row).filter(|dir| dir.has_children()) {
+                    dir.is_loaded = true;
+                    let entries = read_dir(dir);
+                    self.files.extend(entries);
+                }
+            }
+        }
+    }
+
+    fn view(&mut self) -> Element<'_, Self::Message> {
+        let columns = table_columns![("name", "Name"), ("kind", "Kind"), ("size", "Size")];
+        let table = Table::new(&mut self.table_state, columns, &mut self.files)
+            .tree()
+            .on_expand(|row, _| Message::DirExpanded(row))
+            .width(Length::Fill)
+            .height(Length::Fill);
+        Container::new(table).padding(20).width(Length::Fill).height(Length::Fill).into()
+    }
+}
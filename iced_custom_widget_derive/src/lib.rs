@@ -0,0 +1,183 @@
+//! Derive macros for `iced_custom_widget`, enabled with its `derive` feature.
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Field, Fields, Ident, Lit, Meta, NestedMeta, Result};
+
+/// Implements `TableData` for a struct with named fields.
+///
+/// Every field is read and written through `serde_json` by its name, and is listed by
+/// `TableData::columns` unless it is marked `#[table(skip)]`. Columns are labelled with
+/// the field name in title case, which `#[table(label = "...")]` overrides. Columns with
+/// `#[table(order = N)]` come first by ascending `N`, the others follow in field order.
+/// `#[table(editable)]` lets the cells of a column be edited.
+///
+/// A field renamed with `#[serde(rename = "...")]` is read and written by its serialized name,
+/// which the exported rows and the saved column layouts use too.
+#[proc_macro_derive(TableData, attributes(table))]
+pub fn derive_table_data(input: TokenStream) -> TokenStream {
+   let input = parse_macro_input!(input as DeriveInput);
+   expand(&input).unwrap_or_else(|error| error.to_compile_error()).into()
+}
+
+struct Column {
+   ident: Ident,
+   name: String,
+   label: String,
+   order: Option<usize>,
+   is_skipped: bool,
+   is_editable: bool,
+}
+
+impl Column {
+   fn from_field(field: &Field) -> Result<Self> {
+      let ident = field.ident.clone().ok_or_else(|| Error::new_spanned(field, "TableData can only be derived for named fields"))?;
+      let field_name = ident.to_string().trim_start_matches("r#").to_string();
+      let mut column = Column {
+         label: title_case(&field_name),
+         name: serde_name(field)?.unwrap_or(field_name),
+         ident,
+         order: None,
+         is_skipped: false,
+         is_editable: false,
+      };
+
+      for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("table")) {
+         let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            meta => return Err(Error::new_spanned(meta, "expected #[table(...)]")),
+         };
+         for nested in list.nested.iter() {
+            match nested {
+               NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => column.is_skipped = true,
+               NestedMeta::Meta(Meta::Path(path)) if path.is_ident("editable") => column.is_editable = true,
+               NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("label") => match &pair.lit {
+                  Lit::Str(label) => column.label = label.value(),
+                  lit => return Err(Error::new_spanned(lit, "expected a string label")),
+               },
+               NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("order") => match &pair.lit {
+                  Lit::Int(order) => column.order = Some(order.base10_parse()?),
+                  lit => return Err(Error::new_spanned(lit, "expected an integer order")),
+               },
+               nested => return Err(Error::new_spanned(nested, "unknown table attribute, expected `label`, `skip`, `order` or `editable`")),
+            }
+         }
+      }
+
+      Ok(column)
+   }
+}
+
+/// Returns the name a field is serialized with, when `#[serde(rename = "...")]` changes it.
+fn serde_name(field: &Field) -> Result<Option<String>> {
+   let mut name = None;
+   for attr in field.attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+      // the other serde attributes are left to serde
+      let list = match attr.parse_meta() {
+         Ok(Meta::List(list)) => list,
+         _ => continue,
+      };
+      for nested in list.nested.iter() {
+         match nested {
+            NestedMeta::Meta(Meta::NameValue(pair)) if pair.path.is_ident("rename") => match &pair.lit {
+               Lit::Str(rename) => name = Some(rename.value()),
+               lit => return Err(Error::new_spanned(lit, "expected a string name")),
+            },
+            NestedMeta::Meta(Meta::List(rename)) if rename.path.is_ident("rename") => {
+               return Err(Error::new_spanned(rename, "TableData needs one name for a field, use `#[serde(rename = \"...\")]`"));
+            }
+            _ => {}
+         }
+      }
+   }
+   Ok(name)
+}
+
+/// Rejects the serde attributes of the struct that rename its fields.
+fn check_serde_container(input: &DeriveInput) -> Result<()> {
+   for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+      if let Ok(Meta::List(list)) = attr.parse_meta() {
+         for nested in list.nested.iter() {
+            if let NestedMeta::Meta(meta) = nested {
+               if meta.path().is_ident("rename_all") {
+                  return Err(Error::new_spanned(meta, "TableData does not support `rename_all`, rename the fields with `#[serde(rename = \"...\")]`"));
+               }
+            }
+         }
+      }
+   }
+   Ok(())
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2> {
+   check_serde_container(input)?;
+   let fields = match &input.data {
+      Data::Struct(data) => match &data.fields {
+         Fields::Named(fields) => &fields.named,
+         _ => return Err(Error::new_spanned(input, "TableData can only be derived for structs with named fields")),
+      },
+      _ => return Err(Error::new_spanned(input, "TableData can only be derived for structs")),
+   };
+   let columns = fields.iter().map(Column::from_field).collect::<Result<Vec<_>>>()?;
+
+   let table = quote!(::iced_custom_widget::components::table);
+   let serde_json = quote!(::iced_custom_widget::__private::serde_json);
+   let getters = columns.iter().map(|Column { ident, name, .. }| {
+      quote! {
+         #name => #serde_json::to_value(&self.#ident).map_err(|error| #table::TableError::InvalidFieldValue(#name.to_owned(), error.to_string())),
+      }
+   });
+   let setters = columns.iter().map(|Column { ident, name, .. }| {
+      quote! {
+         #name => {
+            self.#ident = #serde_json::from_value(value).map_err(|error| #table::TableError::InvalidFieldType(#name.to_owned(), error.to_string()))?;
+            Ok(())
+         }
+      }
+   });
+
+   let mut ordered: Vec<&Column> = columns.iter().filter(|column| !column.is_skipped).collect();
+   ordered.sort_by_key(|column| column.order.unwrap_or(usize::MAX));
+   let table_columns = ordered.iter().map(|Column { name, label, is_editable, .. }| {
+      quote! {
+         #table::TableColumn::new(#name).label(#label).editable(#is_editable)
+      }
+   });
+
+   let ident = &input.ident;
+   let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+   Ok(quote! {
+      impl #impl_generics #table::TableData for #ident #ty_generics #where_clause {
+         fn get_field_value(&self, field_name: &str) -> #table::TableResult<#serde_json::Value> {
+            match field_name {
+               #(#getters)*
+               _ => Err(#table::TableError::InvalidFieldName(field_name.to_owned())),
+            }
+         }
+
+         fn set_field_value(&mut self, field_name: &str, value: #serde_json::Value) -> #table::TableResult<()> {
+            match field_name {
+               #(#setters)*
+               _ => Err(#table::TableError::InvalidFieldName(field_name.to_owned())),
+            }
+         }
+
+         fn columns() -> ::std::vec::Vec<#table::TableColumn> {
+            vec![#(#table_columns),*]
+         }
+      }
+   })
+}
+
+/// Turns `responsible_user` into `Responsible User`.
+fn title_case(name: &str) -> String {
+   name
+      .split('_')
+      .filter(|word| !word.is_empty())
+      .map(|word| {
+         let mut chars = word.chars();
+         chars.next().map_or_else(String::new, |first| first.to_uppercase().chain(chars).collect())
+      })
+      .collect::<Vec<_>>()
+      .join(" ")
+}
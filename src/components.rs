@@ -10,12 +10,12 @@ pub mod toggler;
 pub mod number_input;
 
 pub use card::Card;
-pub use grid::Grid;
+pub use grid::{Grid, VirtualGrid};
 pub use icon::{Icons, Icon};
 pub use icon_brand::{IconBrands, IconBrand};
 pub use stack::{Overflow, Stack};
 pub use stepper::Stepper;
 pub use tab::Tab;
-pub use table::{Table, TableColumn, TableData, TableError, TableOptions, TableOrder, TableResult};
+pub use table::{CellKind, Table, TableColumn, TableData, TableError, TableOptions, TableOrder, TableResult};
 pub use toggler::Toggler;
 pub use number_input::NumberInput;
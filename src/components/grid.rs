@@ -1,19 +1,268 @@
+mod placement;
+mod track;
+mod virtual_grid;
+
+use crate::styles::grid::StyleSheet;
 use iced_graphics::Primitive;
+use placement::Placement;
+use track::Track;
 use iced_native::{
-   container, event::{self, Event}, layout::{flex::{self, Axis}, Limits, Node}, mouse, overlay, 
-   Align, Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+   container, event::{self, Event}, keyboard, layout::{flex::{self, Axis}, Limits, Node}, mouse, overlay, 
+   Align, Background, Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Vector, Widget,
 };
+use std::cell::RefCell;
+use std::collections::BTreeSet;
+use std::rc::Rc;
+
+pub use virtual_grid::{VirtualGrid, VirtualState};
+
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// How the columns of a grid are chosen from the width available to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Columns {
+   /// A number of columns, each as wide as its widest child.
+   Fixed(usize),
+   /// As many columns of the given width as fit, at least one.
+   Width(u16),
+   /// As many columns as fit at their `min` width, grown up to `max` to share the room left,
+   /// like CSS `repeat(auto-fill, minmax(min, max))`. Columns left empty keep their room.
+   AutoFill { min: u16, max: u16 },
+   /// Like [`Columns::AutoFill`], but the columns left empty are dropped and the others
+   /// grow over their room, like CSS `auto-fit`.
+   AutoFit { min: u16, max: u16 },
+}
+
+impl Columns {
+   /// Returns the columns within `width` for children spanning `count` columns in all.
+   ///
+   /// No more columns than the children span are made, and one for each of them within
+   /// an unbounded width, e.g. in a `Row`.
+   fn tracks(self, width: f32, gap: f32, count: usize) -> Vec<Track> {
+      let count = count.max(1);
+      let fitting = |min: f32| if width.is_finite() { (((width + gap) / (min + gap)).floor() as usize).max(1) } else { count };
+      match self {
+         Columns::Fixed(columns) => vec![Track::Shrink; columns.max(1)],
+         Columns::Width(column_width) => {
+            let column_width = f32::from(column_width.max(1));
+            vec![Track::Fixed(column_width); fitting(column_width).min(count)]
+         }
+         Columns::AutoFill { min, max } | Columns::AutoFit { min, max } => {
+            let (min, max) = (f32::from(min.max(1)), f32::from(max.max(min)));
+            let columns = fitting(min);
+            // auto-fill sizes the columns as if the empty ones were there, auto-fit drops them
+            let shared_by = match self {
+               Columns::AutoFill { .. } => columns,
+               _ => columns.min(count),
+            };
+            let shared = (width - gap * (shared_by - 1) as f32) / shared_by as f32;
+            vec![Track::Fixed(shared.max(min).min(max)); columns.min(count)]
+         }
+      }
+   }
+}
+
+/// A child of a grid, with the cells it is placed over and its alignment within them.
+pub struct Cell<'a, Message, Renderer> {
+   content: Element<'a, Message, Renderer>,
+   placement: Placement,
+}
+
+impl<'a, Message, Renderer> Cell<'a, Message, Renderer> {
+   pub fn new<E>(content: E) -> Self
+   where
+      E: Into<Element<'a, Message, Renderer>>,
+   {
+      Self {
+         content: content.into(),
+         placement: Placement::default(),
+      }
+   }
+
+   /// Places the cell at `row` and `column`, the cells without a position flow around it.
+   pub fn at(mut self, row: usize, column: usize) -> Self {
+      self.placement.position = Some((row, column));
+      self
+   }
+
+   pub fn span(mut self, row_span: usize, column_span: usize) -> Self {
+      self.placement.row_span = row_span;
+      self.placement.column_span = column_span;
+      self
+   }
+
+   pub fn align_x(mut self, align: Align) -> Self {
+      self.placement.align_x = Some(align);
+      self
+   }
+
+   pub fn align_y(mut self, align: Align) -> Self {
+      self.placement.align_y = Some(align);
+      self
+   }
+}
+
+/// The selection of a grid and the item highlighted by the keyboard.
+#[derive(Debug, Clone, Default)]
+pub struct State {
+   selection: BTreeSet<usize>,
+   highlighted: Option<usize>,
+   /// The item a Shift-click or Shift with the arrow keys selects from.
+   selection_anchor: Option<usize>,
+   is_focused: bool,
+   modifiers: keyboard::Modifiers,
+   drag: Option<Drag>,
+}
+
+/// A press on an item, which drags it once the cursor moves.
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+   item: usize,
+   origin: Point,
+   /// Where the item was grabbed, from its top left corner.
+   grab: Vector,
+   cursor: Point,
+   /// The place the item would be dropped at, set once it is dragged.
+   target: Option<usize>,
+}
+
+/// An item dragged from one grid and dropped on another, the grids being told apart by their ids.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Transfer {
+   pub source: String,
+   pub item: usize,
+   pub target: String,
+   /// The place of the item among those of the target grid.
+   pub index: usize,
+}
+
+/// Lets items be dragged between the grids sharing it, see [`Grid::drag_group`].
+/// It holds the item dragged out of a grid until another one takes it.
+#[derive(Debug, Clone, Default)]
+pub struct DragGroup(Rc<RefCell<Option<(String, usize)>>>);
+
+impl DragGroup {
+   pub fn new() -> Self {
+      Self::default()
+   }
+}
+
+impl State {
+   pub fn new() -> Self {
+      Self::default()
+   }
+
+   pub fn highlighted(&self) -> Option<usize> {
+      self.highlighted
+   }
+
+   pub fn set_highlighted(&mut self, item: Option<usize>) {
+      self.highlighted = item;
+      self.selection_anchor = item;
+   }
+
+   pub fn selected_items(&self) -> impl Iterator<Item = usize> + '_ {
+      self.selection.iter().copied()
+   }
+
+   pub fn is_selected(&self, item: usize) -> bool {
+      self.selection.contains(&item)
+   }
+
+   pub fn set_selection(&mut self, items: impl IntoIterator<Item = usize>) {
+      self.selection = items.into_iter().collect();
+   }
+
+   pub fn clear_selection(&mut self) {
+      self.selection.clear();
+   }
+
+   pub fn is_focused(&self) -> bool {
+      self.is_focused
+   }
+
+   /// Highlights and selects `item` alone.
+   fn select(&mut self, item: usize) {
+      self.selection = std::iter::once(item).collect();
+      self.set_highlighted(Some(item));
+   }
+
+   /// Selects the items between the anchor and `item`, both included, and highlights `item`.
+   fn select_range(&mut self, item: usize) {
+      let anchor = self.selection_anchor.unwrap_or(item);
+      self.selection = (anchor.min(item)..=anchor.max(item)).collect();
+      self.highlighted = Some(item);
+      self.selection_anchor = Some(anchor);
+   }
+
+   fn toggle(&mut self, item: usize) {
+      if !self.selection.remove(&item) {
+         self.selection.insert(item);
+      }
+      self.set_highlighted(Some(item));
+   }
+}
+
+/// Where the arrow keys move the highlight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+   Left,
+   Right,
+   Up,
+   Down,
+}
+
+/// Returns the item nearest to `from` in `direction`, among the bounds of the items.
+/// Items out of line with `from` count as farther, so that the highlight stays on its row or column.
+fn nearest(bounds: &[Rectangle], from: usize, direction: Direction) -> Option<usize> {
+   let center = |bounds: &Rectangle| (bounds.x + bounds.width / 2.0, bounds.y + bounds.height / 2.0);
+   let (x, y) = center(bounds.get(from)?);
+   bounds.iter().enumerate().filter(|&(idx, _)| idx != from).filter_map(|(idx, item)| {
+      let (item_x, item_y) = center(item);
+      let (along, across) = match direction {
+         Direction::Left => (x - item_x, item_y - y),
+         Direction::Right => (item_x - x, item_y - y),
+         Direction::Up => (y - item_y, item_x - x),
+         Direction::Down => (item_y - y, item_x - x),
+      };
+      if along > 0.5 {
+         Some((idx, along + across.abs() * 2.0))
+      } else {
+         None
+      }
+   })
+   .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+   .map(|(idx, _)| idx)
+}
 
 /// A container that produces a grid layout.
 /// A scrollable, 2D array of widgets.
-pub struct Grid<'a, Message, Renderer> {
+pub struct Grid<'a, Message, Renderer: self::Renderer> {
+   id: String,
+   state: Option<&'a mut State>,
+   on_activate: Option<Box<dyn Fn(usize) -> Message>>,
+   on_selection_change: Option<Box<dyn Fn(Vec<usize>) -> Message>>,
+   on_reorder: Option<Box<dyn Fn(usize, usize) -> Message>>,
+   on_transfer: Option<Box<dyn Fn(Transfer) -> Message>>,
+   drag_group: Option<DragGroup>,
+   multi_select: bool,
+   style: <Renderer as self::Renderer>::Style,
    width: Length,
    height: Length,
-   spacing: u16,
+   column_gap: u16,
+   row_gap: u16,
    padding: u16,
-   columns: Option<usize>,
-   column_width: Option<u16>,
+   columns: Option<Columns>,
+   /// The columns used below a width, narrowest first.
+   breakpoints: Vec<(u16, Columns)>,
+   column_template: Vec<Length>,
+   row_template: Vec<Length>,
+   /// `None` to center the children within columns of a [`Columns::Width`], and to start them otherwise.
+   align_x: Option<Align>,
+   align_y: Align,
    children: Vec<Element<'a, Message, Renderer>>,
+   /// Where each child is placed, in the order of `children`.
+   placements: Vec<Placement>,
 }
 
 impl<'a, Message, Renderer> Grid<'a, Message, Renderer>
@@ -25,17 +274,99 @@ where
    }
 
    pub fn with_children(children: Vec<Element<'a, Message, Renderer>>) -> Self {
+      let placements = vec![Placement::default(); children.len()];
       Self {
+         id: String::new(),
+         state: None,
+         on_activate: None,
+         on_selection_change: None,
+         on_reorder: None,
+         on_transfer: None,
+         drag_group: None,
+         multi_select: false,
+         style: <Renderer as self::Renderer>::Style::default(),
          width: Length::Fill,
          height: Length::Shrink,
-         spacing: Renderer::DEFAULT_SPACING,
+         column_gap: Renderer::DEFAULT_SPACING,
+         row_gap: Renderer::DEFAULT_SPACING,
          padding: Renderer::DEFAULT_PADDING,
          columns: None,
-         column_width: None,
+         breakpoints: Vec::new(),
+         column_template: Vec::new(),
+         row_template: Vec::new(),
+         align_x: None,
+         align_y: Align::Start,
          children,
+         placements,
       }
    }
 
+   /// Lets the items of the grid be selected with clicks and the arrow keys, keeping the selection in `state`.
+   pub fn selectable(mut self, state: &'a mut State) -> Self {
+      self.state = Some(state);
+      self
+   }
+
+   /// Lets Ctrl-click toggle an item and Shift-click or Shift with the arrow keys select a range of them.
+   pub fn multi_select(mut self, multi_select: bool) -> Self {
+      self.multi_select = multi_select;
+      self
+   }
+
+   /// Publishes the message for the highlighted item when Enter is pressed.
+   pub fn on_activate<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(usize) -> Message,
+   {
+      self.on_activate = Some(Box::new(f));
+      self
+   }
+
+   pub fn on_selection_change<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(Vec<usize>) -> Message,
+   {
+      self.on_selection_change = Some(Box::new(f));
+      self
+   }
+
+   /// Lets the items be dragged to another place, publishing the message with the place of the item
+   /// and the one it is moved to, as for `Vec::remove` then `Vec::insert`. The other items make room
+   /// for it while it is dragged. Dragging needs the [`State`] of [`Grid::selectable`].
+   pub fn on_reorder<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(usize, usize) -> Message,
+   {
+      self.on_reorder = Some(Box::new(f));
+      self
+   }
+
+   /// Names the grid in the [`Transfer`] of items dragged out of or onto it.
+   pub fn id(mut self, id: impl Into<String>) -> Self {
+      self.id = id.into();
+      self
+   }
+
+   /// Lets items be dragged between this grid and the others of `group`.
+   pub fn drag_group(mut self, group: DragGroup) -> Self {
+      self.drag_group = Some(group);
+      self
+   }
+
+   /// Publishes the message when an item of another grid of the drag group is dropped on this one.
+   pub fn on_transfer<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(Transfer) -> Message,
+   {
+      self.on_transfer = Some(Box::new(f));
+      self
+   }
+
+   pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+      self.style = style.into();
+      self
+   }
+
    pub fn width(mut self, width: Length) -> Self {
       self.width = width;
       self
@@ -51,8 +382,20 @@ where
       self
    }
 
+   /// Sets both the column and the row gap.
    pub fn spacing(mut self, spacing: u16) -> Self {
-      self.spacing = spacing;
+      self.column_gap = spacing;
+      self.row_gap = spacing;
+      self
+   }
+
+   pub fn column_gap(mut self, column_gap: u16) -> Self {
+      self.column_gap = column_gap;
+      self
+   }
+
+   pub fn row_gap(mut self, row_gap: u16) -> Self {
+      self.row_gap = row_gap;
       self
    }
 
@@ -60,13 +403,59 @@ where
       if columns == 0 {
          self.columns = None;
       } else {
-         self.columns = Some(columns);
+         self.columns = Some(Columns::Fixed(columns));
       }
       self
    }
 
+   /// Fills the width with as many columns of `column_width` as fit, the children centered within them.
    pub fn column_width(mut self, column_width: u16) -> Self {
-      self.column_width = Some(column_width);
+      self.columns = Some(Columns::Width(column_width));
+      self
+   }
+
+   /// Fills the width with as many columns as fit, see [`Columns::AutoFill`].
+   pub fn auto_fill(mut self, min: u16, max: u16) -> Self {
+      self.columns = Some(Columns::AutoFill { min, max });
+      self
+   }
+
+   /// Fills the width with the columns the children need, see [`Columns::AutoFit`].
+   pub fn auto_fit(mut self, min: u16, max: u16) -> Self {
+      self.columns = Some(Columns::AutoFit { min, max });
+      self
+   }
+
+   /// Uses `columns` while the grid is narrower than `width`, e.g. `.breakpoint(600, Columns::Fixed(2))`.
+   /// The narrowest breakpoint wider than the grid applies, the other columns above them all.
+   pub fn breakpoint(mut self, width: u16, columns: Columns) -> Self {
+      let idx = self.breakpoints.iter().position(|&(max_width, _)| max_width > width).unwrap_or(self.breakpoints.len());
+      self.breakpoints.insert(idx, (width, columns));
+      self
+   }
+
+   /// Sizes each column like a CSS grid track: `Units` like `px`, `Shrink` like `auto`,
+   /// and `Fill` or `FillPortion` like `fr`. The template is used over the `columns` of the grid.
+   pub fn column_template(mut self, columns: Vec<Length>) -> Self {
+      self.column_template = columns;
+      self
+   }
+
+   /// Sizes the rows like [`Grid::column_template`], the rows past the template shrink.
+   pub fn row_template(mut self, rows: Vec<Length>) -> Self {
+      self.row_template = rows;
+      self
+   }
+
+   /// Aligns the children horizontally within their cells, unless their [`Cell`] sets it.
+   pub fn align_x(mut self, align: Align) -> Self {
+      self.align_x = Some(align);
+      self
+   }
+
+   /// Aligns the children vertically within their cells, unless their [`Cell`] sets it.
+   pub fn align_y(mut self, align: Align) -> Self {
+      self.align_y = align;
       self
    }
 
@@ -75,8 +464,201 @@ where
       E: Into<Element<'a, Message, Renderer>>,
    {
       self.children.push(child.into());
+      self.placements.push(Placement::default());
+      self
+   }
+
+   /// Adds a child spanning `row_span` rows and `column_span` columns, placed in the first cells it fits in.
+   pub fn push_span<E>(self, child: E, row_span: usize, column_span: usize) -> Self
+   where
+      E: Into<Element<'a, Message, Renderer>>,
+   {
+      self.push_cell(Cell::new(child).span(row_span, column_span))
+   }
+
+   /// Adds a child at `row` and `column`, spanning `row_span` rows and `column_span` columns.
+   /// The children without a position flow around it.
+   pub fn push_at<E>(self, child: E, row: usize, column: usize, row_span: usize, column_span: usize) -> Self
+   where
+      E: Into<Element<'a, Message, Renderer>>,
+   {
+      self.push_cell(Cell::new(child).at(row, column).span(row_span, column_span))
+   }
+
+   pub fn push_cell(mut self, cell: Cell<'a, Message, Renderer>) -> Self {
+      self.children.push(cell.content);
+      self.placements.push(cell.placement);
       self
    }
+
+   fn is_draggable(&self) -> bool {
+      self.on_reorder.is_some() || self.drag_group.is_some()
+   }
+
+   /// The order the children are laid out in, the dragged one moved to where it would be dropped.
+   fn display_order(&self) -> Vec<usize> {
+      let mut order: Vec<usize> = (0..self.children.len()).collect();
+      if let Some(Drag { item, target: Some(target), .. }) = self.state.as_ref().and_then(|state| state.drag) {
+         if item < order.len() && target < order.len() {
+            let item = order.remove(item);
+            order.insert(target, item);
+         }
+      }
+      order
+   }
+
+   /// Follows the dragged item, returns whether the event belongs to the drag.
+   fn update_drag(&mut self, event: &Event, layout: Layout<'_>, cursor_position: Point, messages: &mut Vec<Message>) -> bool {
+      let order = self.display_order();
+      let (id, is_draggable) = (self.id.clone(), self.is_draggable());
+      let state = match self.state.as_mut() {
+         Some(state) => state,
+         None => return false,
+      };
+      let mut drag = match state.drag {
+         Some(drag) => drag,
+         None => return false,
+      };
+      match event {
+         Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+            if drag.target.is_none() {
+               if !is_draggable || cursor_position.distance(drag.origin) <= DRAG_THRESHOLD {
+                  return false;
+               }
+               drag.target = Some(drag.item);
+               if let Some(group) = &self.drag_group {
+                  *group.0.borrow_mut() = Some((id, drag.item));
+               }
+            }
+            drag.cursor = cursor_position;
+            // the items laid out in display order make room where the cursor is
+            if let Some(child) = layout.children().position(|child| child.bounds().contains(cursor_position)) {
+               drag.target = order.iter().position(|&idx| idx == child);
+            }
+            state.drag = Some(drag);
+            true
+         }
+         Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+            state.drag = None;
+            let target = match drag.target {
+               Some(target) => target,
+               None => return false,
+            };
+            if layout.bounds().contains(cursor_position) {
+               if let Some(group) = &self.drag_group {
+                  group.0.borrow_mut().take();
+               }
+               if let (Some(on_reorder), true) = (&self.on_reorder, target != drag.item) {
+                  messages.push(on_reorder(drag.item, target));
+               }
+            }
+            true
+         }
+         _ => false,
+      }
+   }
+
+   /// Takes the item of another grid of the drag group dropped on this one.
+   fn take_transfer(&mut self, layout: Layout<'_>, cursor_position: Point, messages: &mut Vec<Message>) {
+      let (group, on_transfer) = match (&self.drag_group, &self.on_transfer) {
+         (Some(group), Some(on_transfer)) if layout.bounds().contains(cursor_position) => (group, on_transfer),
+         _ => return,
+      };
+      let source = match group.0.borrow_mut().take() {
+         Some(source) => source,
+         None => return,
+      };
+      let index = layout.children().position(|child| child.bounds().contains(cursor_position)).unwrap_or(self.children.len());
+      messages.push(on_transfer(Transfer {
+         source: source.0,
+         item: source.1,
+         target: self.id.clone(),
+         index,
+      }));
+   }
+
+   fn selection_changed(&self, messages: &mut Vec<Message>) {
+      if let Some((on_selection_change, state)) = self.on_selection_change.as_ref().zip(self.state.as_ref()) {
+         messages.push(on_selection_change(state.selected_items().collect()));
+      }
+   }
+
+   /// Selects the item clicked, or toggles it or selects up to it with Ctrl or Shift.
+   fn click_item(&mut self, item: usize, messages: &mut Vec<Message>) {
+      let multi_select = self.multi_select;
+      let state = match self.state.as_mut() {
+         Some(state) => state,
+         None => return,
+      };
+      match state.modifiers {
+         modifiers if multi_select && modifiers.is_command_pressed() => state.toggle(item),
+         modifiers if multi_select && modifiers.shift => state.select_range(item),
+         _ => state.select(item),
+      }
+      self.selection_changed(messages);
+   }
+
+   /// Moves the highlight with the navigation keys, returns whether the key is one of them.
+   fn navigate(&mut self, key_code: keyboard::KeyCode, modifiers: keyboard::Modifiers, layout: Layout<'_>, messages: &mut Vec<Message>) -> bool {
+      use keyboard::KeyCode;
+
+      let count = self.children.len();
+      let (multi_select, can_activate) = (self.multi_select, self.on_activate.is_some());
+      let state = match self.state.as_mut() {
+         Some(state) if count > 0 => state,
+         _ => return false,
+      };
+      let current = state.highlighted.filter(|&item| item < count);
+      let bounds: Vec<Rectangle> = layout.children().map(|child| child.bounds()).collect();
+      let direction = match key_code {
+         KeyCode::Left => Some(Direction::Left),
+         KeyCode::Right => Some(Direction::Right),
+         KeyCode::Up => Some(Direction::Up),
+         KeyCode::Down => Some(Direction::Down),
+         _ => None,
+      };
+      let item = match (key_code, current, direction) {
+         (KeyCode::Enter, Some(item), _) | (KeyCode::NumpadEnter, Some(item), _) if can_activate => {
+            messages.extend(self.on_activate.as_ref().map(|on_activate| on_activate(item)));
+            return true;
+         }
+         (KeyCode::Space, Some(item), _) if multi_select => {
+            state.toggle(item);
+            self.selection_changed(messages);
+            return true;
+         }
+         (KeyCode::A, _, _) if multi_select && modifiers.is_command_pressed() => {
+            state.set_selection(0..count);
+            self.selection_changed(messages);
+            return true;
+         }
+         (_, Some(item), Some(direction)) => nearest(&bounds, item, direction).unwrap_or(item),
+         (_, None, Some(_)) | (KeyCode::Home, _, _) => 0,
+         (KeyCode::End, _, _) => count - 1,
+         _ => return false,
+      };
+
+      // Ctrl moves the highlight alone, Shift selects up to it
+      if multi_select && modifiers.shift {
+         state.select_range(item);
+      } else if multi_select && modifiers.is_command_pressed() {
+         state.highlighted = Some(item);
+         return true;
+      } else {
+         state.select(item);
+      }
+      self.selection_changed(messages);
+      true
+   }
+}
+
+impl<'a, Message, Renderer> Default for Grid<'a, Message, Renderer>
+where
+   Renderer: self::Renderer,
+{
+   fn default() -> Self {
+      Self::new()
+   }
 }
 
 impl<'a, Message, Renderer> Widget<Message, Renderer> for Grid<'a, Message, Renderer>
@@ -93,101 +675,98 @@ where
 
    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
       if self.children.is_empty() {
-         Node::new(Size::ZERO)
-      } else {
-         let padding = f32::from(self.padding);
-         let spacing = f32::from(self.spacing);
-         let limits = limits.width(self.width).height(self.height);
-         if let Some(columns) = self.columns {
-            let mut column_widths = Vec::<f32>::with_capacity(columns);
-
-            for (column, element) in (0..columns).cycle().zip(&self.children) {
-               if let Some(column_width) = column_widths.get_mut(column) {
-                  *column_width = column_width.max(element.layout(renderer, &limits).size().width);
-               } else {
-                  column_widths.insert(column, element.layout(renderer, &limits).size().width);
-               }
-            }
+         return Node::new(Size::ZERO);
+      }
+      let padding = f32::from(self.padding);
+      let (column_gap, row_gap) = (f32::from(self.column_gap), f32::from(self.row_gap));
+      let limits = limits.width(self.width).height(self.height);
+      let max = limits.pad(padding).max();
 
-            let column_aligns = std::iter::once(&0.)
-               .chain(column_widths.iter().take(column_widths.len() - 1))
-               .scan(0., |state, width| {
-                  *state += width;
-                  Some(*state)
-               });
+      // auto-fit keeps the columns the children could fill
+      let spanned_columns = self.placements.iter().map(|placement| placement.column_span.max(1)).sum();
+      let breakpoint = self.breakpoints.iter().find(|&&(width, _)| max.width < f32::from(width)).map(|&(_, columns)| columns);
+      let align_x = self.align_x.unwrap_or(match breakpoint.or(self.columns) {
+         Some(Columns::Width(_)) if self.column_template.is_empty() || breakpoint.is_some() => Align::Center,
+         _ => Align::Start,
+      });
+      let column_tracks = match (breakpoint, self.columns) {
+         (Some(rule), _) => rule.tracks(max.width, column_gap, spanned_columns),
+         (None, _) if !self.column_template.is_empty() => self.column_template.iter().map(|&length| Track::from(length)).collect(),
+         (None, Some(rule)) => rule.tracks(max.width, column_gap, spanned_columns),
+         (None, None) => return flex::resolve(Axis::Horizontal, renderer, &limits, padding, 8., Align::Start, &self.children),
+      };
+      // the areas are placed in display order, and kept in the order of the children
+      let order = self.display_order();
+      let placements: Vec<Placement> = order.iter().map(|&idx| self.placements[idx]).collect();
+      let placed = placement::place(&placements, column_tracks.len());
+      let mut areas = placed.clone();
+      for (&area, &idx) in placed.iter().zip(&order) {
+         areas[idx] = area;
+      }
+      let rows = areas.iter().map(|area| area.row + area.row_span).max().unwrap_or(0).max(self.row_template.len());
+      let row_tracks: Vec<Track> = (0..rows).map(|row| self.row_template.get(row).map_or(Track::Shrink, |&length| Track::from(length))).collect();
+      let span = |sizes: &[f32], range: std::ops::Range<usize>, gap: f32| sizes[range.clone()].iter().sum::<f32>() + gap * range.len().saturating_sub(1) as f32;
+      // the filling tracks of a shrinking grid shrink to their children
+      let available = |length: Length, size: f32| if length == Length::Shrink || !size.is_finite() { None } else { Some(size) };
 
-            let mut nodes = Vec::with_capacity(self.children.len());
-            let mut grid_height = 0.;
-            let mut row_height = 0.;
-            let mut offset = 0.;
-
-            for ((column, column_align), element) in column_aligns.enumerate().cycle().zip(&self.children) {
-               let mut node = element.layout(renderer, &limits);
-               let size = node.size();
-               if column == 0 {
-                  grid_height += row_height;
-                  row_height = 0.;
-                  offset = 0.;
-                  node.move_to(Point::new(column_align + padding, grid_height + padding));
-               } else {
-                  offset += spacing;
-                  node.move_to(Point::new(column_align + padding + offset, grid_height + padding));
-               }
-               row_height = row_height.max(size.height + spacing);
-               nodes.push(node);
+      // each child is laid out again only when its node could change within the new room: a
+      // shrinking child keeps a node which fits, a filling one is laid out for every other room
+      let mut laid_out: Vec<Option<(Node, Size)>> = vec![None; self.children.len()];
+      let mut lay_out = |idx: usize, room: Size| -> Node {
+         let child = &self.children[idx];
+         let is_shrinking = child.width() == Length::Shrink && child.height() == Length::Shrink;
+         match &laid_out[idx] {
+            Some((node, laid_out_in)) if *laid_out_in == room || (is_shrinking && node.size().width <= room.width && node.size().height <= room.height) => node.clone(),
+            _ => {
+               let node = child.layout(renderer, &Limits::new(Size::ZERO, room));
+               laid_out[idx] = Some((node.clone(), room));
+               node
             }
+         }
+      };
 
-            grid_height += row_height;
-            let grid_width: f32 = column_widths.into_iter().sum();
-
-            Node::with_children(
-               Size::new(
-                  grid_width + (padding * 2.) + (spacing * (columns as f32 - 1.)),
-                  grid_height + padding,
-               ),
-               nodes,
-            )
-         } else if let Some(column_width) = self.column_width {
-            let column_width = f32::from(column_width);
-            let max_width = limits.max().width;
-            let columns = (max_width / column_width).floor() as u16;
-            let mut nodes = Vec::with_capacity(self.children.len());
-            let mut grid_height = 0.;
-            let mut row_height = 0.;
-            let mut offset = 0.;
-
-            for ((idx, column), element) in (0..columns).cycle().enumerate().zip(&self.children) {
-               let mut node = element.layout(renderer, &limits);
-               let size = node.size();
-               offset = (column_width - size.width) / 2.;
-               if column == 0 {
-                  grid_height += row_height;
-                  row_height = 0.;
-               }
-               if padding != 0. {
-                  offset = padding;
-               }
-               if idx == column as usize {
-                  node.move_to(Point::new((column as f32) * column_width + offset, grid_height + padding));
-                  row_height = row_height.max(size.height + padding);
-               } else {
-                  node.move_to(Point::new((column as f32) * column_width + offset, grid_height + spacing));
-                  row_height = row_height.max(size.height + spacing);
-               }
-               nodes.push(node);
-            }
-            grid_height += row_height;
-            let grid_width = (columns as f32) * column_width;
+      // the columns are sized to the children over columns that are not fixed
+      let widths: Vec<(std::ops::Range<usize>, f32)> = areas.iter().enumerate().filter(|(_, area)| track::fixed_span(&column_tracks, area.columns(), column_gap).is_none()).map(|(idx, area)| {
+         (area.columns(), lay_out(idx, max).size().width)
+      }).collect();
+      let column_widths = track::size_tracks(&column_tracks, widths, column_gap, available(self.width, max.width));
 
-            if padding != 0. {
-               Node::with_children(Size::new(grid_width + padding, grid_height + padding), nodes)
-            } else {
-               Node::with_children(Size::new(grid_width, grid_height + padding), nodes)
-            }
-         } else {
-            flex::resolve(Axis::Horizontal, renderer, &limits, padding, 8., Align::Start, &self.children)
+      // then the rows to the children laid out within their columns
+      let mut nodes: Vec<Node> = areas.iter().enumerate().map(|(idx, area)| {
+         let height = track::fixed_span(&row_tracks, area.rows(), row_gap).unwrap_or(max.height);
+         lay_out(idx, Size::new(span(&column_widths, area.columns(), column_gap), height))
+      }).collect();
+      let heights = areas.iter().zip(&nodes).map(|(area, node)| (area.rows(), node.size().height)).collect();
+      let row_heights = track::size_tracks(&row_tracks, heights, row_gap, available(self.height, max.height));
+
+      let offsets = |sizes: &[f32], gap: f32| -> Vec<f32> {
+         sizes.iter().scan(padding, |offset, size| {
+            let start = *offset;
+            *offset += size + gap;
+            Some(start)
+         }).collect()
+      };
+      let (column_x, row_y) = (offsets(&column_widths, column_gap), offsets(&row_heights, row_gap));
+      let aligned = |align: Align, free: f32| match align {
+         Align::Start => 0.0,
+         Align::Center => free.max(0.0) / 2.0,
+         Align::End => free.max(0.0),
+      };
+
+      for (idx, ((node, area), placement)) in nodes.iter_mut().zip(&areas).zip(&self.placements).enumerate() {
+         let size = Size::new(span(&column_widths, area.columns(), column_gap), span(&row_heights, area.rows(), row_gap));
+         // filling children take the height of the rows they span
+         if self.children[idx].height() != Length::Shrink {
+            *node = lay_out(idx, size);
          }
+         let x = aligned(placement.align_x.unwrap_or(align_x), size.width - node.size().width);
+         let y = aligned(placement.align_y.unwrap_or(self.align_y), size.height - node.size().height);
+         node.move_to(Point::new(column_x[area.column] + x, row_y[area.row] + y));
       }
+
+      let grid_width = span(&column_widths, 0..column_widths.len(), column_gap);
+      let grid_height = span(&row_heights, 0..rows, row_gap);
+      Node::with_children(Size::new(grid_width, grid_height).pad(padding), nodes)
    }
 
    fn draw(
@@ -198,7 +777,17 @@ where
       cursor_position: Point,
       viewport: &Rectangle,
    ) -> Renderer::Output {
-      self::Renderer::draw(renderer, defaults, layout, cursor_position, viewport, &self.children)
+      let no_selection = BTreeSet::new();
+      let (highlighted, selection) = match self.state.as_ref() {
+         Some(state) => (state.highlighted.filter(|_| state.is_focused), &state.selection),
+         None => (None, &no_selection),
+      };
+      // the ghost of the dragged item follows the cursor, from where it was grabbed
+      let dragged = self.state.as_ref().and_then(|state| state.drag).filter(|drag| drag.target.is_some()).and_then(|drag| {
+         let bounds = layout.children().nth(drag.item)?.bounds();
+         Some((drag.item, drag.cursor - drag.grab - bounds.position()))
+      });
+      self::Renderer::draw(renderer, defaults, layout, cursor_position, viewport, &self.children, highlighted, selection, dragged, &self.style)
    }
 
    fn hash_layout(&self, state: &mut Hasher) {
@@ -208,6 +797,16 @@ where
       TypeId::of::<Marker>().hash(state);
 
       self.padding.hash(state);
+      self.column_gap.hash(state);
+      self.row_gap.hash(state);
+      self.columns.hash(state);
+      self.breakpoints.hash(state);
+      self.column_template.hash(state);
+      self.row_template.hash(state);
+      self.align_x.hash(state);
+      self.align_y.hash(state);
+      self.placements.hash(state);
+      self.display_order().hash(state);
       self.children.iter().for_each(|child| {
          child.hash_layout(state);
       });
@@ -222,13 +821,63 @@ where
       renderer: &Renderer,
       clipboard: Option<&dyn Clipboard>,
    ) -> event::Status {
-      let event_status = event::Status::Ignored;
-      self
+      if self.update_drag(&event, layout, cursor_position, messages) {
+         // the children let go of the press without taking it as a click
+         if let Event::Mouse(mouse::Event::ButtonReleased(_)) = event {
+            for (child, layout) in self.children.iter_mut().zip(layout.children()) {
+               child.on_event(event.clone(), layout, Point::new(-1.0, -1.0), messages, renderer, clipboard);
+            }
+         }
+         return event::Status::Captured;
+      }
+
+      let event_status = self
          .children
          .iter_mut()
          .zip(layout.children())
          .map(|(child, layout)| child.on_event(event.clone(), layout, cursor_position, messages, renderer, clipboard))
-         .fold(event_status, event::Status::merge)
+         .fold(event::Status::Ignored, event::Status::merge);
+
+      match (&event, &self.drag_group) {
+         (Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)), _) => self.take_transfer(layout, cursor_position, messages),
+         // an item dropped outside of the grids of the group is dropped for good
+         (Event::Mouse(mouse::Event::ButtonPressed(_)), Some(group)) => drop(group.0.borrow_mut().take()),
+         _ => {}
+      }
+
+      let count = self.children.len();
+      let is_draggable = self.is_draggable();
+      let state = match self.state.as_mut() {
+         Some(state) => state,
+         None => return event_status,
+      };
+      state.selection = state.selection.range(..count).copied().collect();
+      match event {
+         Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => state.modifiers = modifiers,
+         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            state.is_focused = layout.bounds().contains(cursor_position);
+            if let Some((item, bounds)) = layout.children().map(|child| child.bounds()).enumerate().find(|(_, bounds)| bounds.contains(cursor_position)) {
+               if is_draggable {
+                  state.drag = Some(Drag {
+                     item,
+                     origin: cursor_position,
+                     grab: cursor_position - bounds.position(),
+                     cursor: cursor_position,
+                     target: None,
+                  });
+               }
+               self.click_item(item, messages);
+            }
+         }
+         Event::Keyboard(keyboard::Event::KeyPressed { key_code, modifiers }) if state.is_focused && event_status == event::Status::Ignored => {
+            let is_navigated = self.navigate(key_code, modifiers, layout, messages);
+            if is_navigated {
+               return event::Status::Captured;
+            }
+         }
+         _ => {}
+      }
+      event_status
    }
 
    fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
@@ -242,9 +891,14 @@ where
 }
 
 pub trait Renderer: iced_native::Renderer + container::Renderer + Sized {
+   type Style: Default;
    const DEFAULT_PADDING: u16;
    const DEFAULT_SPACING: u16;
 
+   /// Draws the children, over the background of the selected ones and under the border
+   /// of the highlighted one. The dragged child is drawn moved by the given offset, over
+   /// a placeholder left at its place.
+   #[allow(clippy::too_many_arguments)]
    fn draw<Message>(
       &mut self,
       defaults: &Self::Defaults,
@@ -252,11 +906,31 @@ pub trait Renderer: iced_native::Renderer + container::Renderer + Sized {
       cursor_position: Point,
       viewport: &Rectangle,
       children: &[Element<'_, Message, Self>],
+      highlighted: Option<usize>,
+      selection: &BTreeSet<usize>,
+      dragged: Option<(usize, Vector)>,
+      style: &<Self as self::Renderer>::Style,
+   ) -> Self::Output;
+
+   /// Draws the items in view of a virtualized grid, laid out as the children of `content`,
+   /// clipped to its bounds, and the scroller, if its items overflow it.
+   #[allow(clippy::too_many_arguments)]
+   fn draw_virtual<Message>(
+      &mut self,
+      defaults: &Self::Defaults,
+      bounds: Rectangle,
+      content: Layout<'_>,
+      cursor_position: Point,
+      items: &[Element<'_, Message, Self>],
+      scroller: Option<Rectangle>,
+      is_scroller_active: bool,
+      style: &<Self as self::Renderer>::Style,
    ) -> Self::Output;
 }
 
 impl Renderer for iced_wgpu::Renderer
 {
+   type Style = Box<dyn StyleSheet>;
    const DEFAULT_PADDING: u16 = 0;
    const DEFAULT_SPACING: u16 = 8;
 
@@ -267,27 +941,131 @@ impl Renderer for iced_wgpu::Renderer
       cursor_position: Point,
       viewport: &Rectangle,
       children: &[Element<'_, Message, Self>],
+      highlighted: Option<usize>,
+      selection: &BTreeSet<usize>,
+      dragged: Option<(usize, Vector)>,
+      style: &<Self as self::Renderer>::Style,
    ) -> Self::Output {
+      let styling = style.active();
       let mut mouse_interaction = mouse::Interaction::default();
+      let mut primitives = Vec::with_capacity(children.len() + 2);
+      let mut ghost_primitive = None;
+
+      if let Some(background) = styling.background {
+         primitives.push(Primitive::Quad {
+            bounds: layout.bounds(),
+            background,
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_radius: 0.0,
+         });
+      }
+      for (idx, (child, layout)) in children.iter().zip(layout.children()).enumerate() {
+         if selection.contains(&idx) {
+            primitives.push(Primitive::Quad {
+               bounds: layout.bounds(),
+               background: styling.selected_background,
+               border_color: Color::TRANSPARENT,
+               border_width: 0.0,
+               border_radius: styling.border_radius,
+            });
+         }
+         let (primitive, new_mouse_interaction) = child.draw(self, defaults, layout, cursor_position, viewport);
+
+         if new_mouse_interaction > mouse_interaction {
+            mouse_interaction = new_mouse_interaction;
+         }
+
+         match dragged {
+            Some((item, translation)) if item == idx => {
+               primitives.push(Primitive::Quad {
+                  bounds: layout.bounds(),
+                  background: styling.placeholder_background,
+                  border_color: Color::TRANSPARENT,
+                  border_width: 0.0,
+                  border_radius: styling.border_radius,
+               });
+               let ghost = Primitive::Quad {
+                  bounds: layout.bounds(),
+                  background: styling.ghost_background,
+                  border_color: styling.highlight_border_color,
+                  border_width: 1.0,
+                  border_radius: styling.border_radius,
+               };
+               ghost_primitive = Some(Primitive::Translate {
+                  translation,
+                  content: Box::new(Primitive::Group { primitives: vec![ghost, primitive] }),
+               });
+            }
+            _ => primitives.push(primitive),
+         }
+      }
+      // the ghost is drawn over the other children
+      if let Some(ghost) = ghost_primitive {
+         primitives.push(ghost);
+         mouse_interaction = mouse::Interaction::Grabbing;
+      }
+      if let Some(layout) = highlighted.and_then(|idx| layout.children().nth(idx)) {
+         primitives.push(Primitive::Quad {
+            bounds: layout.bounds(),
+            background: Background::Color(Color::TRANSPARENT),
+            border_color: styling.highlight_border_color,
+            border_width: styling.highlight_border_width,
+            border_radius: styling.border_radius,
+         });
+      }
+
+      (Primitive::Group { primitives }, mouse_interaction)
+   }
+
+   fn draw_virtual<Message>(
+      &mut self,
+      defaults: &Self::Defaults,
+      bounds: Rectangle,
+      content: Layout<'_>,
+      cursor_position: Point,
+      items: &[Element<'_, Message, Self>],
+      scroller: Option<Rectangle>,
+      is_scroller_active: bool,
+      style: &<Self as self::Renderer>::Style,
+   ) -> Self::Output {
+      let styling = style.active();
+      let mut mouse_interaction = mouse::Interaction::default();
+      let mut primitives = Vec::with_capacity(3);
+
+      if let Some(background) = styling.background {
+         primitives.push(Primitive::Quad {
+            bounds,
+            background,
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_radius: 0.0,
+         });
+      }
+      let content = items.iter().zip(content.children()).map(|(item, layout)| {
+         let (primitive, new_mouse_interaction) = item.draw(self, defaults, layout, cursor_position, &bounds);
+
+         if new_mouse_interaction > mouse_interaction {
+            mouse_interaction = new_mouse_interaction;
+         }
+         primitive
+      }).collect();
+      primitives.push(Primitive::Clip {
+         bounds,
+         offset: Vector::new(0, 0),
+         content: Box::new(Primitive::Group { primitives: content }),
+      });
+      if let Some(scroller) = scroller {
+         primitives.push(Primitive::Quad {
+            bounds: scroller,
+            background: Background::Color(if is_scroller_active { styling.scroller_active_color } else { styling.scroller_color }),
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_radius: scroller.width / 2.0,
+         });
+      }
 
-      (
-         Primitive::Group {
-            primitives: children
-               .iter()
-               .zip(layout.children())
-               .map(|(child, layout)| {
-                  let (primitive, new_mouse_interaction) = child.draw(self, defaults, layout, cursor_position, viewport);
-
-                  if new_mouse_interaction > mouse_interaction {
-                     mouse_interaction = new_mouse_interaction;
-                  }
-
-                  primitive
-               })
-               .collect(),
-         },
-         mouse_interaction,
-      )
+      (Primitive::Group { primitives }, mouse_interaction)
    }
 }
 
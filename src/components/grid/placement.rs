@@ -0,0 +1,82 @@
+use iced_native::Align;
+use smart_default::SmartDefault;
+
+/// Where a child asked to be placed: at a cell or in the first free one, over a number of tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SmartDefault)]
+pub(crate) struct Placement {
+   /// The row and column of the top left cell, `None` for an auto-placed child.
+   pub position: Option<(usize, usize)>,
+   #[default = 1]
+   pub row_span: usize,
+   #[default = 1]
+   pub column_span: usize,
+   /// The alignment within the cells, `None` for the one of the grid.
+   pub align_x: Option<Align>,
+   pub align_y: Option<Align>,
+}
+
+/// The cells a child is placed over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Area {
+   pub row: usize,
+   pub column: usize,
+   pub row_span: usize,
+   pub column_span: usize,
+}
+
+impl Area {
+   pub fn rows(&self) -> std::ops::Range<usize> {
+      self.row..self.row + self.row_span
+   }
+
+   pub fn columns(&self) -> std::ops::Range<usize> {
+      self.column..self.column + self.column_span
+   }
+}
+
+/// Places the children over `columns` columns. Children with a position are placed first,
+/// they may overlap. The others fill the first free cells they fit in, searched from the top left
+/// for each of them, so that smaller children flow into the gaps left by spanning ones.
+pub(crate) fn place(placements: &[Placement], columns: usize) -> Vec<Area> {
+   let columns = columns.max(1);
+   let mut occupied: Vec<Vec<bool>> = Vec::new();
+   let occupy = |occupied: &mut Vec<Vec<bool>>, area: Area| {
+      if occupied.len() < area.row + area.row_span {
+         occupied.resize(area.row + area.row_span, vec![false; columns]);
+      }
+      for row in area.rows() {
+         for column in area.columns() {
+            occupied[row][column] = true;
+         }
+      }
+   };
+   let is_free = |occupied: &[Vec<bool>], area: Area| area.rows().all(|row| occupied.get(row).map_or(true, |cells| area.columns().all(|column| !cells[column])));
+
+   let mut areas: Vec<Option<Area>> = vec![None; placements.len()];
+   for (area, placement) in areas.iter_mut().zip(placements) {
+      if let Some((row, column)) = placement.position {
+         let column_span = placement.column_span.max(1).min(columns);
+         let placed = Area {
+            row,
+            // a child past the last column is moved back into the grid
+            column: column.min(columns - column_span),
+            row_span: placement.row_span.max(1),
+            column_span,
+         };
+         occupy(&mut occupied, placed);
+         *area = Some(placed);
+      }
+   }
+   for (area, placement) in areas.iter_mut().zip(placements) {
+      if area.is_none() {
+         let (row_span, column_span) = (placement.row_span.max(1), placement.column_span.max(1).min(columns));
+         let placed = (0..)
+            .flat_map(|row| (0..=columns - column_span).map(move |column| Area { row, column, row_span, column_span }))
+            .find(|&candidate| is_free(&occupied, candidate))
+            .unwrap();
+         occupy(&mut occupied, placed);
+         *area = Some(placed);
+      }
+   }
+   areas.into_iter().flatten().collect()
+}
@@ -0,0 +1,83 @@
+use iced_native::Length;
+use std::ops::Range;
+
+/// How a row or column of a grid is sized, like a CSS grid track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Track {
+   /// A size in pixels, like `px`.
+   Fixed(f32),
+   /// The size of the largest child within the track, like `auto`.
+   Shrink,
+   /// A portion of the room left by the other tracks, like `fr`.
+   Fill(u16),
+}
+
+impl From<Length> for Track {
+   fn from(length: Length) -> Self {
+      match length {
+         Length::Units(units) => Track::Fixed(f32::from(units)),
+         Length::Shrink => Track::Shrink,
+         Length::Fill => Track::Fill(1),
+         Length::FillPortion(portion) => Track::Fill(portion),
+      }
+   }
+}
+
+/// Returns the size of `tracks` spanned by `range` when they are all fixed, gaps included.
+pub(crate) fn fixed_span(tracks: &[Track], range: Range<usize>, gap: f32) -> Option<f32> {
+   let span = range.len();
+   tracks[range].iter().map(|track| match track {
+      Track::Fixed(size) => Some(*size),
+      _ => None,
+   }).sum::<Option<f32>>().map(|size| size + gap * span.saturating_sub(1) as f32)
+}
+
+/// Sizes `tracks` to the children within them, given as the tracks they span and their size.
+///
+/// Children within one track are fitted first, then spanning children grow the shrinking tracks
+/// they span evenly by what they lack, the children spanning fewer tracks first. The filling
+/// tracks share the room left within `available`, they shrink to their children without it.
+pub(crate) fn size_tracks(tracks: &[Track], mut items: Vec<(Range<usize>, f32)>, gap: f32, available: Option<f32>) -> Vec<f32> {
+   let mut sizes: Vec<f32> = tracks.iter().map(|track| match track {
+      Track::Fixed(size) => *size,
+      _ => 0.0,
+   }).collect();
+   let grows = |track: &Track| match track {
+      Track::Fixed(_) => false,
+      Track::Shrink => true,
+      Track::Fill(_) => available.is_none(),
+   };
+
+   items.sort_by_key(|(range, _)| range.len());
+   for (range, size) in items {
+      if range.is_empty() || range.end > tracks.len() {
+         continue;
+      }
+      // children over a filling track take the room it is given
+      if available.is_some() && tracks[range.clone()].iter().any(|track| matches!(track, Track::Fill(_))) {
+         continue;
+      }
+      let growing: Vec<usize> = range.clone().filter(|&track| grows(&tracks[track])).collect();
+      let current = sizes[range.clone()].iter().sum::<f32>() + gap * (range.len() - 1) as f32;
+      if !growing.is_empty() && size > current {
+         let extra = (size - current) / growing.len() as f32;
+         growing.into_iter().for_each(|track| sizes[track] += extra);
+      }
+   }
+
+   if let Some(available) = available {
+      let portions: u32 = tracks.iter().map(|track| match track {
+         Track::Fill(portion) => u32::from(*portion),
+         _ => 0,
+      }).sum();
+      if portions > 0 {
+         let remaining = (available - sizes.iter().sum::<f32>() - gap * tracks.len().saturating_sub(1) as f32).max(0.0);
+         for (size, track) in sizes.iter_mut().zip(tracks) {
+            if let Track::Fill(portion) = track {
+               *size = remaining * f32::from(*portion) / portions as f32;
+            }
+         }
+      }
+   }
+   sizes
+}
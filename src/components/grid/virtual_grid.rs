@@ -0,0 +1,346 @@
+use super::Renderer;
+use iced_native::{
+   event::{self, Event}, layout::{Limits, Node}, mouse, Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+use std::cell::{Cell, RefCell, RefMut};
+use std::ops::Range;
+
+const DEFAULT_CELL_SIZE: u16 = 64;
+const SCROLL_LINE_HEIGHT: f32 = 60.0;
+const SCROLLER_WIDTH: f32 = 8.0;
+const SCROLLER_MARGIN: f32 = 2.0;
+const MIN_SCROLLER_HEIGHT: f32 = 20.0;
+
+/// The scroll offset of a [`VirtualGrid`].
+#[derive(Debug, Clone, Default)]
+pub struct VirtualState {
+   offset: f32,
+   /// The item to show once the grid knows how its items are laid out.
+   scroll_target: Option<usize>,
+   /// Where the scroller was grabbed, from its top.
+   scroller_grabbed_at: Option<f32>,
+   /// The start and end of the items in view at the last layout, which are the only ones built.
+   visible: Cell<(usize, usize)>,
+}
+
+impl VirtualState {
+   pub fn new() -> Self {
+      Self::default()
+   }
+
+   pub fn offset(&self) -> f32 {
+      self.offset
+   }
+
+   /// Scrolls the least needed to show the item at `index`.
+   pub fn scroll_to(&mut self, index: usize) {
+      self.scroll_target = Some(index);
+   }
+
+   pub fn scroll_to_top(&mut self) {
+      self.offset = 0.0;
+      self.scroll_target = None;
+   }
+}
+
+/// How the items of a virtualized grid are laid out within its bounds.
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+   columns: usize,
+   cell: Size,
+   spacing: f32,
+   padding: f32,
+   content_height: f32,
+}
+
+impl Metrics {
+   fn row_top(&self, row: usize) -> f32 {
+      self.padding + (self.cell.height + self.spacing) * row as f32
+   }
+
+   fn max_offset(&self, bounds: Rectangle) -> f32 {
+      (self.content_height - bounds.height).max(0.0)
+   }
+
+   /// The offset showing the item at `index` from `offset`, scrolled the least.
+   fn offset_showing(&self, index: usize, offset: f32, bounds: Rectangle) -> f32 {
+      let top = self.row_top(index / self.columns) - self.padding;
+      let bottom = top + self.cell.height + self.padding * 2.0;
+      if top < offset {
+         top
+      } else if bottom > offset + bounds.height {
+         bottom - bounds.height
+      } else {
+         offset
+      }
+   }
+}
+
+/// A grid of `count` items of one size, for many of them: only the items in view are built,
+/// by the closure given to [`VirtualGrid::new`], laid out and drawn. The grid scrolls itself.
+pub struct VirtualGrid<'a, Message, Renderer: self::Renderer> {
+   state: &'a mut VirtualState,
+   count: usize,
+   view: Box<dyn Fn(usize) -> Element<'a, Message, Renderer> + 'a>,
+   /// The items built by `view`, and the start and end of the range they were built for.
+   items: RefCell<Vec<Element<'a, Message, Renderer>>>,
+   built: Cell<(usize, usize)>,
+   cell_width: u16,
+   cell_height: u16,
+   width: Length,
+   height: Length,
+   spacing: u16,
+   padding: u16,
+   style: <Renderer as self::Renderer>::Style,
+}
+
+impl<'a, Message, Renderer> VirtualGrid<'a, Message, Renderer>
+where
+   Renderer: self::Renderer,
+{
+   /// Shows `count` items, the one at an index being built by `view`.
+   pub fn new<F>(state: &'a mut VirtualState, count: usize, view: F) -> Self
+   where
+      F: 'a + Fn(usize) -> Element<'a, Message, Renderer>,
+   {
+      Self {
+         state,
+         count,
+         view: Box::new(view),
+         items: RefCell::new(Vec::new()),
+         built: Cell::new((0, 0)),
+         cell_width: DEFAULT_CELL_SIZE,
+         cell_height: DEFAULT_CELL_SIZE,
+         width: Length::Fill,
+         height: Length::Fill,
+         spacing: Renderer::DEFAULT_SPACING,
+         padding: Renderer::DEFAULT_PADDING,
+         style: <Renderer as self::Renderer>::Style::default(),
+      }
+   }
+
+   /// Sets the size of every cell, the items are centered within them.
+   pub fn cell_size(mut self, width: u16, height: u16) -> Self {
+      self.cell_width = width;
+      self.cell_height = height;
+      self
+   }
+
+   pub fn width(mut self, width: Length) -> Self {
+      self.width = width;
+      self
+   }
+
+   pub fn height(mut self, height: Length) -> Self {
+      self.height = height;
+      self
+   }
+
+   pub fn spacing(mut self, spacing: u16) -> Self {
+      self.spacing = spacing;
+      self
+   }
+
+   pub fn padding(mut self, padding: u16) -> Self {
+      self.padding = padding;
+      self
+   }
+
+   pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
+      self.style = style.into();
+      self
+   }
+
+   fn metrics(&self, width: f32) -> Metrics {
+      let (spacing, padding) = (f32::from(self.spacing), f32::from(self.padding));
+      let cell = Size::new(f32::from(self.cell_width.max(1)), f32::from(self.cell_height.max(1)));
+      // an unbounded grid, e.g. in a row, shows a single column rather than every item side by side
+      let columns = if width.is_finite() { (((width - padding * 2.0 - SCROLLER_WIDTH + spacing) / (cell.width + spacing)).floor() as usize).max(1) } else { 1 };
+      let rows = (self.count + columns - 1) / columns;
+      let content_height = if rows == 0 { 0.0 } else { (cell.height + spacing) * rows as f32 - spacing } + padding * 2.0;
+      Metrics { columns, cell, spacing, padding, content_height }
+   }
+
+   /// The offset the grid is scrolled by, the item to show, if any, scrolled into view.
+   fn offset(&self, metrics: &Metrics, bounds: Rectangle) -> f32 {
+      let offset = match self.state.scroll_target {
+         Some(index) => metrics.offset_showing(index, self.state.offset, bounds),
+         None => self.state.offset,
+      };
+      offset.max(0.0).min(metrics.max_offset(bounds))
+   }
+
+   /// The indices of the items in view, scrolled by `offset` within `height`.
+   fn visible(&self, metrics: &Metrics, offset: f32, height: f32) -> Range<usize> {
+      let stride = metrics.cell.height + metrics.spacing;
+      let first_row = ((offset - metrics.padding) / stride).floor().max(0.0) as usize;
+      let last_row = ((offset + height - metrics.padding) / stride).floor().max(0.0) as usize;
+      (first_row * metrics.columns).min(self.count)..((last_row + 1) * metrics.columns).min(self.count)
+   }
+
+   /// Returns the items in view at the last layout, built once for each grid.
+   fn items(&self) -> RefMut<'_, Vec<Element<'a, Message, Renderer>>> {
+      let (start, end) = self.state.visible.get();
+      let mut items = self.items.borrow_mut();
+      if self.built.replace((start, end)) != (start, end) {
+         *items = (start..end).map(|index| (self.view)(index)).collect();
+      }
+      items
+   }
+
+   /// The bounds of the scroller, when the items overflow the grid.
+   fn scroller(&self, bounds: Rectangle) -> Option<Rectangle> {
+      let metrics = self.metrics(bounds.width);
+      let max_offset = metrics.max_offset(bounds);
+      if max_offset <= 0.0 {
+         return None;
+      }
+      let track = bounds.height - SCROLLER_MARGIN * 2.0;
+      let height = (track * bounds.height / metrics.content_height).max(MIN_SCROLLER_HEIGHT).min(track);
+      let y = bounds.y + SCROLLER_MARGIN + (track - height) * self.offset(&metrics, bounds) / max_offset;
+      Some(Rectangle {
+         x: bounds.x + bounds.width - SCROLLER_WIDTH - SCROLLER_MARGIN,
+         y,
+         width: SCROLLER_WIDTH,
+         height,
+      })
+   }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for VirtualGrid<'a, Message, Renderer>
+where
+   Renderer: self::Renderer,
+{
+   fn width(&self) -> Length {
+      self.width
+   }
+
+   fn height(&self) -> Length {
+      self.height
+   }
+
+   fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
+      let limits = limits.width(self.width).height(self.height);
+      let metrics = self.metrics(limits.max().width);
+      let width = metrics.padding * 2.0 + (metrics.cell.width + metrics.spacing) * metrics.columns as f32 - metrics.spacing + SCROLLER_WIDTH;
+      let size = limits.resolve(Size::new(width, metrics.content_height));
+      let offset = self.offset(&metrics, Rectangle::with_size(size));
+
+      // only the items in view are laid out, within a node of the size of all of them
+      let visible = self.visible(&metrics, offset, size.height);
+      self.state.visible.set((visible.start, visible.end));
+      let items = self.items().iter().zip(visible).map(|(item, index)| {
+         let mut node = item.layout(renderer, &Limits::new(Size::ZERO, metrics.cell));
+         let (row, column) = (index / metrics.columns, index % metrics.columns);
+         let x = metrics.padding + (metrics.cell.width + metrics.spacing) * column as f32 + (metrics.cell.width - node.size().width).max(0.0) / 2.0;
+         let y = metrics.row_top(row) + (metrics.cell.height - node.size().height).max(0.0) / 2.0;
+         node.move_to(Point::new(x, y));
+         node
+      }).collect();
+      let mut content = Node::with_children(Size::new(size.width, metrics.content_height), items);
+      content.move_to(Point::new(0.0, -offset));
+      Node::with_children(size, vec![content])
+   }
+
+   fn draw(
+      &self,
+      renderer: &mut Renderer,
+      defaults: &Renderer::Defaults,
+      layout: Layout<'_>,
+      cursor_position: Point,
+      _viewport: &Rectangle,
+   ) -> Renderer::Output {
+      let bounds = layout.bounds();
+      let content = layout.children().next().unwrap();
+      let items = self.items();
+      let scroller = self.scroller(bounds);
+      let is_scroller_active = self.state.scroller_grabbed_at.is_some() || scroller.map_or(false, |scroller| scroller.contains(cursor_position));
+      self::Renderer::draw_virtual(renderer, defaults, bounds, content, cursor_position, &items, scroller, is_scroller_active, &self.style)
+   }
+
+   fn hash_layout(&self, state: &mut Hasher) {
+      use std::{any::TypeId, hash::Hash};
+
+      struct Marker;
+      TypeId::of::<Marker>().hash(state);
+
+      self.count.hash(state);
+      self.cell_width.hash(state);
+      self.cell_height.hash(state);
+      self.spacing.hash(state);
+      self.padding.hash(state);
+      (self.state.offset as u32).hash(state);
+      self.state.scroll_target.hash(state);
+   }
+
+   fn on_event(
+      &mut self,
+      event: Event,
+      layout: Layout<'_>,
+      cursor_position: Point,
+      messages: &mut Vec<Message>,
+      renderer: &Renderer,
+      clipboard: Option<&dyn Clipboard>,
+   ) -> event::Status {
+      let bounds = layout.bounds();
+      let metrics = self.metrics(bounds.width);
+      let scroller = self.scroller(bounds);
+      self.state.offset = self.offset(&metrics, bounds);
+      self.state.scroll_target = None;
+
+      if let Some(grabbed_at) = self.state.scroller_grabbed_at {
+         match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+               if let Some(scroller) = scroller {
+                  let track = bounds.height - SCROLLER_MARGIN * 2.0 - scroller.height;
+                  let ratio = if track > 0.0 { (cursor_position.y - grabbed_at - bounds.y - SCROLLER_MARGIN) / track } else { 0.0 };
+                  self.state.offset = (ratio * metrics.max_offset(bounds)).max(0.0).min(metrics.max_offset(bounds));
+               }
+               return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+               self.state.scroller_grabbed_at = None;
+               return event::Status::Captured;
+            }
+            _ => {}
+         }
+      }
+
+      match event {
+         Event::Mouse(mouse::Event::WheelScrolled { delta }) if bounds.contains(cursor_position) => {
+            let delta_y = match delta {
+               mouse::ScrollDelta::Lines { y, .. } => y * SCROLL_LINE_HEIGHT,
+               mouse::ScrollDelta::Pixels { y, .. } => y,
+            };
+            self.state.offset = (self.state.offset - delta_y).max(0.0).min(metrics.max_offset(bounds));
+            return event::Status::Captured;
+         }
+         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            if let Some(scroller) = scroller.filter(|scroller| scroller.contains(cursor_position)) {
+               self.state.scroller_grabbed_at = Some(cursor_position.y - scroller.y);
+               return event::Status::Captured;
+            }
+         }
+         _ => {}
+      }
+
+      // the parts of items scrolled out of the grid are not under the cursor
+      let cursor_position = if bounds.contains(cursor_position) { cursor_position } else { Point::new(-1.0, -1.0) };
+      let content = layout.children().next().unwrap();
+      self.items()
+         .iter_mut()
+         .zip(content.children())
+         .map(|(item, layout)| item.on_event(event.clone(), layout, cursor_position, messages, renderer, clipboard))
+         .fold(event::Status::Ignored, event::Status::merge)
+   }
+}
+
+impl<'a, Message, Renderer> From<VirtualGrid<'a, Message, Renderer>> for Element<'a, Message, Renderer>
+where
+   Renderer: 'a + self::Renderer,
+   Message: 'a,
+{
+   fn from(grid: VirtualGrid<'a, Message, Renderer>) -> Element<'a, Message, Renderer> {
+      Element::new(grid)
+   }
+}
@@ -6,7 +6,7 @@ use iced_native::{
 };
 pub use crate::utils::Icons;
 
-const ICON_FONT: Font = Font::External {
+pub(crate) const ICON_FONT: Font = Font::External {
     name: "Line Awesome",
     bytes: include_bytes!("../../assets/fonts/la-solid-900.ttf"),
 };
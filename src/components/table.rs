@@ -1,8 +1,30 @@
 #[macro_use]
 mod macros;
+mod cell;
+mod context_menu;
+mod editor;
 mod table_column;
+mod scroll;
+mod sort;
+mod source;
 mod error;
+mod export;
+mod filter;
+mod group;
+mod pagination;
+mod tooltip;
+mod tree;
 mod table;
+pub use cell::CellKind;
+pub use context_menu::MenuItem;
 pub use error::{Result as TableResult, TableError};
-pub use table::{State, Table, TableData};
-pub use table_column::{TableColumn, TableOptions, TableOrder};
\ No newline at end of file
+pub use export::ExportFormat;
+pub use filter::Filter;
+pub use group::Aggregate;
+pub use sort::{compare_values, SortKey};
+pub use source::{LazySource, Query, TableSource};
+pub use tree::TreeData;
+pub use table::{CellView, OnEdit, OnRow, OnSort, RowMenu, RowStyle, SelectionMode, State, Table, TableData};
+#[cfg(feature = "derive")]
+pub use iced_custom_widget_derive::TableData;
+pub use table_column::{ColumnLayout, TableColumn, TableOptions, TableOrder, TextOverflow};
\ No newline at end of file
@@ -0,0 +1,162 @@
+use crate::utils::Icons;
+use serde_json::Value;
+use smart_default::SmartDefault;
+
+pub(crate) const PROGRESS_BAR_WIDTH: f32 = 80.0;
+
+/// How the values of a column are shown in its cells.
+#[derive(Debug, Clone, SmartDefault)]
+pub enum CellKind {
+   /// The value as plain text, strings without their quotes.
+   #[default]
+   Text,
+   /// A number rounded to `precision` decimals, followed by an optional unit.
+   Number { precision: Option<usize>, unit: Option<String> },
+   /// A check box for booleans.
+   Checkbox,
+   /// One icon for `true` and an optional one for `false`.
+   Icon { on: Icons, off: Option<Icons> },
+   /// A bar filled by a value between 0 and 100.
+   ProgressBar,
+   /// A unix timestamp in seconds or an ISO 8601 string, shown with a
+   /// `strftime`-like format supporting `%Y`, `%m`, `%d`, `%H`, `%M` and `%S`.
+   Date { format: String },
+   /// The text returned by the given function.
+   Format(fn(&Value) -> String),
+}
+
+impl CellKind {
+   pub fn number(precision: usize) -> Self {
+      CellKind::Number { precision: Some(precision), unit: None }
+   }
+
+   pub fn number_with_unit(precision: usize, unit: impl Into<String>) -> Self {
+      CellKind::Number { precision: Some(precision), unit: Some(unit.into()) }
+   }
+
+   pub fn icon(on: Icons, off: Option<Icons>) -> Self {
+      CellKind::Icon { on, off }
+   }
+
+   pub fn date(format: impl Into<String>) -> Self {
+      CellKind::Date { format: format.into() }
+   }
+
+   /// Returns the text of a cell, or `None` for kinds that are drawn as shapes.
+   pub fn format(&self, value: &Value) -> Option<String> {
+      match self {
+         CellKind::Text => Some(plain_text(value)),
+         CellKind::Number { precision, unit } => Some(match value.as_f64() {
+            Some(number) => {
+               let number = match precision {
+                  Some(precision) => format!("{:.*}", precision, number),
+                  None => plain_text(value),
+               };
+               match unit {
+                  Some(unit) => format!("{} {}", number, unit),
+                  None => number,
+               }
+            }
+            None => plain_text(value),
+         }),
+         CellKind::Checkbox | CellKind::Icon { .. } | CellKind::ProgressBar => None,
+         CellKind::Date { format } => Some(format_date(value, format).unwrap_or_else(|| plain_text(value))),
+         CellKind::Format(f) => Some(f(value)),
+      }
+   }
+}
+
+impl PartialEq for CellKind {
+   fn eq(&self, other: &Self) -> bool {
+      use CellKind::*;
+      match (self, other) {
+         (Text, Text) | (Checkbox, Checkbox) | (ProgressBar, ProgressBar) => true,
+         (Number { precision: a, unit: a_unit }, Number { precision: b, unit: b_unit }) => a == b && a_unit == b_unit,
+         (Icon { on: a, off: a_off }, Icon { on: b, off: b_off }) => a == b && a_off == b_off,
+         (Date { format: a }, Date { format: b }) => a == b,
+         // functions are compared by address, two copies of the same function may differ
+         (Format(a), Format(b)) => *a as usize == *b as usize,
+         _ => false,
+      }
+   }
+}
+
+pub(crate) fn plain_text(value: &Value) -> String {
+   match value {
+      Value::Null => String::new(),
+      Value::String(s) => s.clone(),
+      value => value.to_string(),
+   }
+}
+
+/// Reads a value between 0 and 100 as a ratio between 0 and 1.
+pub(crate) fn progress(value: &Value) -> f32 {
+   value.as_f64().map_or(0.0, |value| (value / 100.0).clamp(0.0, 1.0) as f32)
+}
+
+fn format_date(value: &Value, format: &str) -> Option<String> {
+   let (date, time) = match value {
+      Value::Number(number) => {
+         let timestamp = number.as_i64()?;
+         let days = timestamp.div_euclid(86_400);
+         let seconds = timestamp.rem_euclid(86_400);
+         (civil_from_days(days), (seconds / 3600, seconds % 3600 / 60, seconds % 60))
+      }
+      Value::String(s) => parse_iso8601(s)?,
+      _ => return None,
+   };
+   let (year, month, day) = date;
+   let (hour, minute, second) = time;
+
+   let mut formatted = String::with_capacity(format.len());
+   let mut chars = format.chars();
+   while let Some(c) = chars.next() {
+      if c != '%' {
+         formatted.push(c);
+         continue;
+      }
+      match chars.next() {
+         Some('Y') => formatted.push_str(&format!("{:04}", year)),
+         Some('m') => formatted.push_str(&format!("{:02}", month)),
+         Some('d') => formatted.push_str(&format!("{:02}", day)),
+         Some('H') => formatted.push_str(&format!("{:02}", hour)),
+         Some('M') => formatted.push_str(&format!("{:02}", minute)),
+         Some('S') => formatted.push_str(&format!("{:02}", second)),
+         Some(other) => {
+            formatted.push('%');
+            formatted.push(other);
+         }
+         None => formatted.push('%'),
+      }
+   }
+   Some(formatted)
+}
+
+type Date = (i64, i64, i64);
+type Time = (i64, i64, i64);
+
+/// Parses `YYYY-MM-DD` with an optional `THH:MM:SS` time part.
+fn parse_iso8601(s: &str) -> Option<(Date, Time)> {
+   let number = |range: std::ops::Range<usize>| s.get(range).and_then(|part| part.parse::<i64>().ok());
+   let date = (number(0..4)?, number(5..7)?, number(8..10)?);
+   let time = if s.len() >= 19 {
+      (number(11..13)?, number(14..16)?, number(17..19)?)
+   } else {
+      (0, 0, 0)
+   };
+   Some((date, time))
+}
+
+/// Converts days since 1970-01-01 into a (year, month, day) date.
+fn civil_from_days(days: i64) -> Date {
+   let z = days + 719_468;
+   let era = z.div_euclid(146_097);
+   let doe = z.rem_euclid(146_097);
+   let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+   let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+   let mp = (5 * doy + 2) / 153;
+   let day = doy - (153 * mp + 2) / 5 + 1;
+   let month = if mp < 10 { mp + 3 } else { mp - 9 };
+   let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+   (year, month, day)
+}
@@ -0,0 +1,114 @@
+use super::table;
+use iced_native::{event::{self, Event}, keyboard, layout::Node, mouse, overlay, Clipboard, Hasher, Layout, Point, Rectangle, Size};
+
+const MIN_MENU_WIDTH: f32 = 120.0;
+
+/// An item of a context menu, its message is published once the item is chosen.
+#[derive(Debug, Clone)]
+pub struct MenuItem<Message> {
+   pub label: String,
+   /// `None` for an item shown greyed out, which cannot be chosen.
+   pub message: Option<Message>,
+}
+
+impl<Message> MenuItem<Message> {
+   pub fn new(label: impl Into<String>, message: Message) -> Self {
+      Self { label: label.into(), message: Some(message) }
+   }
+
+   pub fn disabled(label: impl Into<String>) -> Self {
+      Self { label: label.into(), message: None }
+   }
+}
+
+/// What a context menu is open on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MenuTarget {
+   /// The record at the given index.
+   Row(usize),
+   /// The shown column at the given index.
+   Header(usize),
+}
+
+/// A context menu open at `position`, the table runs the chosen item on the next press.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct OpenMenu {
+   pub target: MenuTarget,
+   pub position: Point,
+   pub chosen: Option<usize>,
+   /// The last press landed on the menu without choosing an item, which keeps it open.
+   pub is_pressed: bool,
+}
+
+impl OpenMenu {
+   pub fn new(target: MenuTarget, position: Point) -> Self {
+      Self { target, position, chosen: None, is_pressed: false }
+   }
+}
+
+/// Lists the items of an open menu, as their label and whether they can be chosen.
+pub(crate) struct ContextMenu<'a, Renderer: table::Renderer> {
+   menu: &'a mut OpenMenu,
+   items: Vec<(String, bool)>,
+   text_size: Option<u16>,
+   padding: u16,
+   font: Renderer::Font,
+   style: &'a <Renderer as table::Renderer>::Style,
+}
+
+impl<'a, Renderer: table::Renderer> ContextMenu<'a, Renderer> {
+   pub fn new(menu: &'a mut OpenMenu, items: Vec<(String, bool)>, text_size: Option<u16>, padding: u16, font: Renderer::Font, style: &'a <Renderer as table::Renderer>::Style) -> Self {
+      Self { menu, items, text_size, padding, font, style }
+   }
+}
+
+impl<'a, Message, Renderer: table::Renderer> overlay::Overlay<Message, Renderer> for ContextMenu<'a, Renderer> {
+   fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let padding = f32::from(self.padding);
+      let item_height = f32::from(text_size) + padding * 2.0;
+      let width = self.items.iter().map(|(label, _)| renderer.measure(label, text_size, self.font, Size::INFINITY).0 + padding * 4.0).fold(MIN_MENU_WIDTH, f32::max);
+
+      let items = (0..self.items.len()).map(|idx| {
+         let mut node = Node::new(Size::new(width, item_height));
+         node.move_to(Point::new(0.0, item_height * idx as f32));
+         node
+      }).collect();
+      let size = Size::new(width, item_height * self.items.len() as f32);
+      // the menu opens towards the free side of the cursor
+      let x = if position.x + size.width > bounds.width { position.x - size.width } else { position.x };
+      let y = if position.y + size.height > bounds.height { position.y - size.height } else { position.y };
+      let mut node = Node::with_children(size, items);
+      node.move_to(Point::new(x.max(0.0), y.max(0.0)));
+      node
+   }
+
+   fn draw(&self, renderer: &mut Renderer, _defaults: &Renderer::Defaults, layout: Layout<'_>, cursor_position: Point) -> Renderer::Output {
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let items: Vec<(Rectangle, &str, bool)> = layout.children().zip(self.items.iter()).map(|(item, (label, is_enabled))| (item.bounds(), label.as_str(), *is_enabled)).collect();
+      let hovered = items.iter().position(|(bounds, ..)| bounds.contains(cursor_position));
+      table::Renderer::draw_menu(renderer, layout.bounds(), &items, hovered, text_size, self.padding, self.font, self.style)
+   }
+
+   fn hash_layout(&self, state: &mut Hasher, position: Point) {
+      use std::hash::Hash;
+      struct Marker;
+      std::any::TypeId::of::<Marker>().hash(state);
+
+      (position.x as u32).hash(state);
+      (position.y as u32).hash(state);
+      self.items.iter().for_each(|(label, _)| label.hash(state));
+   }
+
+   fn on_event(&mut self, event: Event, layout: Layout<'_>, cursor_position: Point, _messages: &mut Vec<Message>, _renderer: &Renderer, _clipboard: Option<&dyn Clipboard>) -> event::Status {
+      match event {
+         Event::Mouse(mouse::Event::ButtonPressed(_)) if layout.bounds().contains(cursor_position) => {
+            self.menu.chosen = layout.children().zip(self.items.iter()).position(|(item, (_, is_enabled))| *is_enabled && item.bounds().contains(cursor_position));
+            self.menu.is_pressed = self.menu.chosen.is_none();
+            event::Status::Captured
+         }
+         Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::Escape, .. }) => event::Status::Captured,
+         _ => event::Status::Ignored,
+      }
+   }
+}
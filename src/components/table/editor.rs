@@ -0,0 +1,249 @@
+use super::{
+   error::TableError,
+   table::{self, OnEdit, TableData},
+};
+use crate::components::{
+   number_input::{self, NumberInput},
+   toggler::Toggler,
+};
+use iced_native::{
+   event::{self, Event}, layout::{Limits, Node}, keyboard, mouse, overlay, text_input,
+   Clipboard, Element, Hasher, Layout, Length, Point, Size, TextInput,
+};
+use serde_json::{Number, Value};
+
+const MIN_EDITOR_WIDTH: f32 = 120.0;
+
+/// The value of a cell while it is edited, which also picks its editor.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Draft {
+   Text(String),
+   Integer(i64),
+   Float(f64),
+   Bool(bool),
+}
+
+impl Draft {
+   fn from_value(value: &Value) -> Option<Self> {
+      match value {
+         Value::Null => Some(Draft::Text(String::new())),
+         Value::String(s) => Some(Draft::Text(s.clone())),
+         Value::Bool(b) => Some(Draft::Bool(*b)),
+         Value::Number(n) => n.as_i64().map(Draft::Integer).or_else(|| n.as_f64().map(Draft::Float)),
+         Value::Array(_) | Value::Object(_) => None,
+      }
+   }
+
+   /// Returns the value of the draft, or why it is not one for `column`.
+   fn to_value(&self, column: &str) -> Result<Value, TableError> {
+      match self {
+         Draft::Text(s) => Ok(Value::String(s.clone())),
+         Draft::Integer(n) => Ok(Value::from(*n)),
+         Draft::Float(n) => Number::from_f64(*n).map(Value::Number).ok_or_else(|| TableError::InvalidFieldValue(column.to_string(), String::from("not a finite number"))),
+         Draft::Bool(b) => Ok(Value::Bool(*b)),
+      }
+   }
+}
+
+/// A cell being edited. It stays in the table state once closed until the
+/// table is rebuilt, so that the key closing it is not handled twice.
+#[derive(Debug, Clone)]
+pub(crate) struct Editing {
+   pub row: usize,
+   pub column: String,
+   pub is_closed: bool,
+   draft: Draft,
+   error: Option<String>,
+   /// The input of text drafts, focused as the editor opens.
+   text_input: text_input::State,
+   number_input: number_input::State,
+}
+
+impl Editing {
+   /// Returns `None` when the value has no editor.
+   pub fn new(row: usize, column: &str, value: &Value) -> Option<Self> {
+      let mut text_input = text_input::State::focused();
+      text_input.move_cursor_to_end();
+
+      Some(Self {
+         row,
+         column: column.to_string(),
+         is_closed: false,
+         draft: Draft::from_value(value)?,
+         error: None,
+         text_input,
+         number_input: number_input::State::new(),
+      })
+   }
+
+   fn content<'a, Renderer: 'a + table::Renderer + number_input::Renderer>(&'a mut self, text_size: u16, padding: u16, font: Renderer::Font) -> Element<'a, Draft, Renderer> {
+      let Editing { draft, text_input, number_input, .. } = self;
+      match draft {
+         Draft::Text(text) => TextInput::new(text_input, "", text, Draft::Text).size(text_size).padding(padding).font(font).into(),
+         Draft::Integer(value) => NumberInput::new(number_input, *value, i64::MAX, Draft::Integer)
+            .bound((i64::MIN, i64::MAX))
+            .size(text_size)
+            .padding(padding)
+            .font(font)
+            .width(Length::Fill)
+            .into(),
+         Draft::Float(value) => NumberInput::new(number_input, *value, f64::MAX, Draft::Float)
+            .bound((f64::MIN, f64::MAX))
+            .size(text_size)
+            .padding(padding)
+            .font(font)
+            .width(Length::Fill)
+            .into(),
+         Draft::Bool(value) => Toggler::new(*value, None, Draft::Bool).size(text_size).width(Length::Shrink).into(),
+      }
+   }
+}
+
+/// Shows the editor of a cell on top of it, with the validation error below.
+pub(crate) struct Editor<'a, T, Message, Renderer: table::Renderer> {
+   editing: &'a mut Editing,
+   record: &'a mut T,
+   cell: Size,
+   on_edit: Option<&'a OnEdit<T, Message>>,
+   text_size: Option<u16>,
+   padding: u16,
+   font: Renderer::Font,
+   style: &'a <Renderer as table::Renderer>::Style,
+}
+
+impl<'a, T, Message, Renderer> Editor<'a, T, Message, Renderer>
+where
+   T: TableData,
+   Renderer: table::Renderer,
+{
+   #[allow(clippy::too_many_arguments)]
+   pub fn new(
+      editing: &'a mut Editing,
+      record: &'a mut T,
+      cell: Size,
+      on_edit: Option<&'a OnEdit<T, Message>>,
+      text_size: Option<u16>,
+      padding: u16,
+      font: Renderer::Font,
+      style: &'a <Renderer as table::Renderer>::Style,
+   ) -> Self {
+      Self { editing, record, cell, on_edit, text_size, padding, font, style }
+   }
+
+   /// Writes the edited value to a copy of the record, which replaces it only when valid.
+   fn commit(&mut self, messages: &mut Vec<Message>) {
+      let column = self.editing.column.clone();
+      let mut record = self.record.clone();
+      let result = self.editing.draft.to_value(&column).and_then(|value| record.set_field_value(&column, value));
+
+      match result {
+         Ok(()) => {
+            *self.record = record;
+            self.editing.is_closed = true;
+            if let Some(on_edit) = self.on_edit {
+               messages.push(on_edit(self.editing.row, &column, self.record));
+            }
+         }
+         Err(error) => self.editing.error = Some(error.to_string()),
+      }
+   }
+}
+
+impl<'a, T, Message, Renderer> overlay::Overlay<Message, Renderer> for Editor<'a, T, Message, Renderer>
+where
+   T: TableData,
+   Renderer: table::Renderer + number_input::Renderer,
+{
+   fn layout(&self, renderer: &Renderer, _bounds: Size, position: Point) -> Node {
+      let padding = f32::from(self.padding);
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let width = self.cell.width.max(MIN_EDITOR_WIDTH);
+      let mut editing = self.editing.clone();
+      let mut content = editing.content::<Renderer>(text_size, self.padding, self.font).layout(renderer, &Limits::new(Size::ZERO, Size::new(width, f32::INFINITY)));
+      let height = self.cell.height.max(content.size().height);
+      let x = if let Draft::Bool(_) = editing.draft { padding } else { 0.0 };
+      content.move_to(Point::new(x, (height - content.size().height) / 2.0));
+      let editor = Node::with_children(Size::new(width, height), vec![content]);
+
+      let mut children = vec![editor];
+      if let Some(error) = &self.editing.error {
+         let (error_width, error_height) = renderer.measure(error, text_size, self.font, Size::new(f32::INFINITY, f32::INFINITY));
+         let mut node = Node::new(Size::new(error_width, error_height).pad(padding));
+         node.move_to(Point::new(0.0, height));
+         children.push(node);
+      }
+
+      let size = children.iter().fold(Size::ZERO, |size, node| {
+         let bounds = node.bounds();
+         Size::new(size.width.max(bounds.x + bounds.width), size.height.max(bounds.y + bounds.height))
+      });
+      let mut node = Node::with_children(size, children);
+      node.move_to(position);
+      node
+   }
+
+   fn draw(&self, renderer: &mut Renderer, defaults: &Renderer::Defaults, layout: Layout<'_>, cursor_position: Point) -> Renderer::Output {
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let mut children = layout.children();
+      let editor_layout = children.next().unwrap();
+      let error = self.editing.error.as_deref().zip(children.next().map(|layout| layout.bounds()));
+      let mut editing = self.editing.clone();
+      let content = editing.content::<Renderer>(text_size, self.padding, self.font).draw(
+         renderer,
+         defaults,
+         editor_layout.children().next().unwrap(),
+         cursor_position,
+         &layout.bounds(),
+      );
+
+      table::Renderer::draw_editor(renderer, editor_layout.bounds(), content, error, text_size, self.padding, self.font, self.style)
+   }
+
+   fn hash_layout(&self, state: &mut Hasher, position: Point) {
+      use std::hash::Hash;
+      struct Marker;
+      std::any::TypeId::of::<Marker>().hash(state);
+
+      (position.x as u32).hash(state);
+      (position.y as u32).hash(state);
+      (self.cell.width as u32).hash(state);
+      (self.cell.height as u32).hash(state);
+      std::mem::discriminant(&self.editing.draft).hash(state);
+      self.editing.error.hash(state);
+   }
+
+   fn on_event(&mut self, event: Event, layout: Layout<'_>, cursor_position: Point, messages: &mut Vec<Message>, renderer: &Renderer, clipboard: Option<&dyn Clipboard>) -> event::Status {
+      if self.editing.is_closed {
+         return event::Status::Ignored;
+      }
+
+      match event {
+         Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::Enter, .. }) | Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::NumpadEnter, .. }) => {
+            self.commit(messages);
+            event::Status::Captured
+         }
+         Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::Escape, .. }) => {
+            self.editing.is_closed = true;
+            event::Status::Captured
+         }
+         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) if !layout.bounds().contains(cursor_position) => {
+            self.commit(messages);
+            event::Status::Ignored
+         }
+         event => {
+            let text_size = self.text_size.unwrap_or(renderer.default_size());
+            let content_layout = layout.children().next().unwrap().children().next().unwrap();
+            let mut drafts = Vec::new();
+            let status = self
+               .editing
+               .content::<Renderer>(text_size, self.padding, self.font)
+               .on_event(event, content_layout, cursor_position, &mut drafts, renderer, clipboard);
+            if let Some(draft) = drafts.pop() {
+               self.editing.draft = draft;
+               self.editing.error = None;
+            }
+            status
+         }
+      }
+   }
+}
@@ -4,12 +4,24 @@ pub type Result<T> = std::result::Result<T, TableError>;
 pub enum TableError {
    // NonRenderableField(String),
    InvalidFieldName(String),
+   /// The field exists but cannot be written.
+   ReadOnlyField(String),
+   /// The new value of a field has the wrong JSON type, with the type that was expected.
+   InvalidFieldType(String, String),
+   /// The new value of a field was rejected, with the reason why.
+   InvalidFieldValue(String, String),
+   /// The rows could not be written in the export format, with the reason why.
+   ExportFailed(String),
 }
 
 impl std::fmt::Display for TableError {
    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
       let msg = match self {
          TableError::InvalidFieldName(field_name) => format!("Invalid field name given: '{}'.", field_name), // TableError::NonRenderableField(field_name) => format!("Could not render field '{}' for which no HTML representation is defined.", field_name),
+         TableError::ReadOnlyField(field_name) => format!("Field '{}' cannot be edited.", field_name),
+         TableError::InvalidFieldType(field_name, expected) => format!("Field '{}' expects {}.", field_name, expected),
+         TableError::InvalidFieldValue(field_name, reason) => format!("Invalid value for field '{}': {}.", field_name, reason),
+         TableError::ExportFailed(reason) => format!("Could not export the table: {}.", reason),
       };
       write!(f, "{}", msg)
    }
@@ -24,6 +36,10 @@ impl std::error::Error for TableError {
       match self {
          TableError::InvalidFieldName(_) => "Invalid field name given.",
          // TableError::NonRenderableField(_) => "Field has no HTML representation defined.",
+         TableError::ReadOnlyField(_) => "Field cannot be edited.",
+         TableError::InvalidFieldType(..) => "Field value has the wrong type.",
+         TableError::InvalidFieldValue(..) => "Field value is invalid.",
+         TableError::ExportFailed(_) => "Table could not be exported.",
       }
    }
 }
@@ -0,0 +1,85 @@
+use super::{
+   cell,
+   error::{Result, TableError},
+   source::TableSource,
+   table::TableData,
+   table_column::TableColumn,
+};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+/// The formats the rows of a table can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+   /// Comma-separated values with a header line, the cells as shown in the table.
+   Csv,
+   /// Tab-separated values without a header line, as pasted into spreadsheets.
+   Tsv,
+   /// An array with an object per row, keyed by column label.
+   Json,
+   /// A sequence with a mapping per row, keyed by column label.
+   Yaml,
+}
+
+/// A record reduced to the exported columns, which serializes as a map in column order.
+struct Record<'a>(Vec<(String, &'a Value)>);
+
+impl<'a> Serialize for Record<'a> {
+   fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+      let mut map = serializer.serialize_map(Some(self.0.len()))?;
+      for (label, value) in self.0.iter() {
+         map.serialize_entry(label, value)?;
+      }
+      map.end()
+   }
+}
+
+/// Writes the given records, in the given order, limited to `columns`. Records not loaded yet are left out.
+pub(crate) fn export<T: TableData, S: TableSource<T> + ?Sized>(data: &S, rows: &[usize], columns: &[TableColumn], format: ExportFormat) -> Result<String> {
+   let values = rows
+      .iter()
+      .filter_map(|&row| data.get(row))
+      .map(|record| columns.iter().map(|column| record.get_field_value(&column.name)).collect::<Result<Vec<_>>>())
+      .collect::<Result<Vec<_>>>()?;
+   let labels: Vec<String> = columns.iter().map(TableColumn::to_string).collect();
+
+   match format {
+      ExportFormat::Csv | ExportFormat::Tsv => {
+         let (separator, escape): (&str, fn(&str) -> String) = match format {
+            ExportFormat::Csv => (",", csv_field),
+            _ => ("\t", tsv_field),
+         };
+         let mut lines = Vec::with_capacity(values.len() + 1);
+         if format == ExportFormat::Csv {
+            lines.push(labels.iter().map(|label| escape(label)).collect::<Vec<_>>().join(separator));
+         }
+         for record in values.iter() {
+            let cells = columns.iter().zip(record).map(|(column, value)| escape(&column.kind.format(value).unwrap_or_else(|| cell::plain_text(value))));
+            lines.push(cells.collect::<Vec<_>>().join(separator));
+         }
+         Ok(lines.join("\n"))
+      }
+      ExportFormat::Json | ExportFormat::Yaml => {
+         let records: Vec<Record> = values.iter().map(|record| Record(labels.iter().cloned().zip(record).collect())).collect();
+         let text = match format {
+            ExportFormat::Json => serde_json::to_string_pretty(&records).map_err(|error| error.to_string()),
+            _ => serde_yaml::to_string(&records).map_err(|error| error.to_string()),
+         };
+         text.map_err(TableError::ExportFailed)
+      }
+   }
+}
+
+/// Quotes a CSV field when it holds a separator, a quote or a line break.
+fn csv_field(text: &str) -> String {
+   if text.contains(&[',', '"', '\n', '\r'][..]) {
+      format!("\"{}\"", text.replace('"', "\"\""))
+   } else {
+      text.to_string()
+   }
+}
+
+/// TSV has no quoting, so tabs and line breaks inside a cell become spaces.
+fn tsv_field(text: &str) -> String {
+   text.replace(&['\t', '\n', '\r'][..], " ")
+}
@@ -0,0 +1,72 @@
+use super::{
+   cell,
+   sort::compare_values,
+   source::TableSource,
+   table::TableData,
+   table_column::TableColumn,
+};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+/// A predicate on the values of a column.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+   /// The value equals the given one.
+   Equals(Value),
+   /// The value lies between the given bounds, both inclusive and each optional.
+   Range(Option<Value>, Option<Value>),
+   /// The text of the value contains the given one, ignoring case.
+   Contains(String),
+   /// The value is the given boolean.
+   Bool(bool),
+}
+
+impl Filter {
+   pub fn range(min: impl Into<Value>, max: impl Into<Value>) -> Self {
+      Filter::Range(Some(min.into()), Some(max.into()))
+   }
+
+   pub fn contains(text: impl Into<String>) -> Self {
+      Filter::Contains(text.into())
+   }
+
+   pub fn matches(&self, value: &Value) -> bool {
+      match self {
+         Filter::Equals(expected) => compare_values(value, expected) == Ordering::Equal,
+         Filter::Range(min, max) => {
+            !value.is_null()
+               && min.as_ref().map_or(true, |min| compare_values(value, min) != Ordering::Less)
+               && max.as_ref().map_or(true, |max| compare_values(value, max) != Ordering::Greater)
+         }
+         Filter::Contains(text) => contains_ignore_case(&cell::plain_text(value), text),
+         Filter::Bool(expected) => value.as_bool() == Some(*expected),
+      }
+   }
+}
+
+/// Returns the indices of the records that match both the query and the column
+/// filters, in their original order. The query matches the text shown in any column.
+/// Records not loaded yet are kept only when nothing filters them.
+pub(crate) fn filter_indices<T: TableData, S: TableSource<T> + ?Sized>(data: &S, columns: &[TableColumn], query: &str, filters: &[(String, Filter)]) -> Vec<usize> {
+   let query = query.trim();
+   (0..data.len())
+      .filter(|&idx| {
+         let record = match data.get(idx) {
+            Some(record) => record,
+            None => return query.is_empty() && filters.is_empty(),
+         };
+         filters.iter().all(|(column, filter)| record.get_field_value(column).map_or(false, |value| filter.matches(&value)))
+            && (query.is_empty()
+               || columns.iter().any(|column| {
+                  record.get_field_value(&column.name).map_or(false, |value| {
+                     let text = column.kind.format(&value).unwrap_or_else(|| cell::plain_text(&value));
+                     contains_ignore_case(&text, query)
+                  })
+               }))
+      })
+      .collect()
+}
+
+fn contains_ignore_case(text: &str, pattern: &str) -> bool {
+   text.to_lowercase().contains(&pattern.to_lowercase())
+}
@@ -0,0 +1,139 @@
+use super::{
+   cell::{self, CellKind},
+   sort::compare_values,
+   source::TableSource,
+   table::TableData,
+   table_column::TableColumn,
+};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ops::Range;
+
+/// A summary of the numeric values of a column, shown in group headers and the totals row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+   /// The number of values that are not `null`.
+   Count,
+   Sum,
+   Average,
+   Min,
+   Max,
+}
+
+impl Aggregate {
+   fn apply(self, values: &[f64]) -> Option<f64> {
+      match self {
+         Aggregate::Count => Some(values.len() as f64),
+         _ if values.is_empty() => None,
+         Aggregate::Sum => Some(values.iter().sum()),
+         Aggregate::Average => Some(values.iter().sum::<f64>() / values.len() as f64),
+         Aggregate::Min => Some(values.iter().copied().fold(f64::INFINITY, f64::min)),
+         Aggregate::Max => Some(values.iter().copied().fold(f64::NEG_INFINITY, f64::max)),
+      }
+   }
+
+   fn label(self) -> &'static str {
+      match self {
+         Aggregate::Count => "Count",
+         Aggregate::Sum => "Sum",
+         Aggregate::Average => "Avg",
+         Aggregate::Min => "Min",
+         Aggregate::Max => "Max",
+      }
+   }
+}
+
+/// The records sharing a value of the grouped column.
+#[derive(Debug, Clone)]
+pub(crate) struct Group {
+   /// The value of the grouped column shared by its records, `Null` for the empty ones.
+   pub value: Value,
+   pub label: String,
+   /// The positions of its records in the rows of the table.
+   pub rows: Range<usize>,
+   /// The text of each aggregate, by column name.
+   pub aggregates: Vec<(String, String)>,
+}
+
+/// A line of the table body: a group header or a record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Line {
+   Group(usize),
+   Record(usize),
+}
+
+/// Moves the rows of each group together, groups ordered by their value and
+/// records keeping their order within a group, and returns the groups.
+pub(crate) fn group_rows<T: TableData, S: TableSource<T> + ?Sized>(data: &S, rows: &mut Vec<usize>, column: &str, columns: &[TableColumn], aggregates: &[(String, Aggregate)]) -> Vec<Group> {
+   let value = |row: usize| data.get(row).and_then(|record| record.get_field_value(column).ok()).unwrap_or(Value::Null);
+   let mut keyed: Vec<(Value, usize)> = rows.iter().map(|&row| (value(row), row)).collect();
+   // null values form the last group
+   keyed.sort_by(|(a, _), (b, _)| match (a.is_null(), b.is_null()) {
+      (false, false) => compare_values(a, b),
+      (a, b) => a.cmp(&b),
+   });
+   *rows = keyed.iter().map(|(_, row)| *row).collect();
+
+   let kind = columns.iter().find(|other| other.name == column).map(|column| &column.kind);
+   let mut groups: Vec<Group> = Vec::new();
+   let mut start = 0;
+   for end in 1..=keyed.len() {
+      if end < keyed.len() && compare_values(&keyed[start].0, &keyed[end].0) == Ordering::Equal && keyed[start].0.is_null() == keyed[end].0.is_null() {
+         continue;
+      }
+      let label = match &keyed[start].0 {
+         Value::Null => String::from("(empty)"),
+         value => kind.and_then(|kind| kind.format(value)).unwrap_or_else(|| cell::plain_text(value)),
+      };
+      groups.push(Group {
+         value: keyed[start].0.clone(),
+         label,
+         aggregates: aggregate_texts(data, &rows[start..end], columns, aggregates),
+         rows: start..end,
+      });
+      start = end;
+   }
+   groups
+}
+
+/// Identifies a group by its value, as values formatted alike may still form distinct groups.
+pub(crate) fn group_key(value: &Value) -> String {
+   value.to_string()
+}
+
+/// Lists the lines of the body, leaving out the records of collapsed groups.
+pub(crate) fn lines(rows: &[usize], groups: &[Group], collapsed: &HashSet<String>) -> Vec<Line> {
+   if groups.is_empty() {
+      return rows.iter().copied().map(Line::Record).collect();
+   }
+   let mut lines = Vec::with_capacity(rows.len() + groups.len());
+   for (idx, group) in groups.iter().enumerate() {
+      lines.push(Line::Group(idx));
+      if !collapsed.contains(&group_key(&group.value)) {
+         lines.extend(rows[group.rows.clone()].iter().copied().map(Line::Record));
+      }
+   }
+   lines
+}
+
+/// Computes the text of each aggregate over the given records.
+pub(crate) fn aggregate_texts<T: TableData, S: TableSource<T> + ?Sized>(data: &S, rows: &[usize], columns: &[TableColumn], aggregates: &[(String, Aggregate)]) -> Vec<(String, String)> {
+   aggregates
+      .iter()
+      .filter_map(|(column, aggregate)| {
+         let values: Vec<f64> = rows.iter().filter_map(|&row| data.get(row)?.get_field_value(column).ok()).filter_map(|value| match aggregate {
+            Aggregate::Count if !value.is_null() => Some(0.0),
+            _ => value.as_f64(),
+         }).collect();
+         let result = aggregate.apply(&values)?;
+         let text = match (aggregate, columns.iter().find(|other| other.name == *column).map(|column| &column.kind)) {
+            (Aggregate::Count, _) => result.to_string(),
+            (_, Some(kind @ CellKind::Number { .. })) => kind.format(&Value::from(result)).unwrap_or_default(),
+            _ if result.fract() == 0.0 => format!("{}", result as i64),
+            _ => format!("{:.2}", result),
+         };
+         Some((column.clone(), format!("{}: {}", aggregate.label(), text)))
+      })
+      .collect()
+}
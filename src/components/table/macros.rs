@@ -5,7 +5,7 @@ macro_rules! table_column {
             name: $a.to_string(),
             label: Some($a.to_string()),
             short_name: Some($a.to_string()),
-            order: Default::default(),
+            ..::std::default::Default::default()
         }
     }};
     ($a:expr, $b:expr) => {{
@@ -13,7 +13,7 @@ macro_rules! table_column {
             name: $a.to_string(),
             label: Some($b.to_string()),
             short_name: Some($a.to_string()),
-            order: Default::default(),
+            ..::std::default::Default::default()
         }
     }};
     ($a:expr, $b:expr, $c:expr) => {
@@ -21,7 +21,7 @@ macro_rules! table_column {
             name: $a.to_string(),
             label: Some($b.to_string()),
             short_name: Some($c.to_string()),
-            order: Default::default(),
+            ..::std::default::Default::default()
         }
     };
 }
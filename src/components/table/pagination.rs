@@ -0,0 +1,99 @@
+use iced_native::Rectangle;
+
+/// How many page numbers the footer shows around the current page.
+const PAGE_SLOTS: usize = 5;
+
+/// A clickable item of the pagination footer. Page numbers are slots whose
+/// page depends on the current one, so that the footer keeps its layout while paging.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PageButton {
+   Size(usize),
+   First,
+   Previous,
+   Slot(usize),
+   Next,
+   Last,
+}
+
+impl PageButton {
+   /// Lists the page size choices followed by the page navigation.
+   pub fn all(page_sizes: &[usize], page_count: usize) -> Vec<Self> {
+      let mut buttons: Vec<Self> = page_sizes.iter().copied().map(PageButton::Size).collect();
+      buttons.extend([PageButton::First, PageButton::Previous].iter().copied());
+      buttons.extend((0..page_count.min(PAGE_SLOTS)).map(PageButton::Slot));
+      buttons.extend([PageButton::Next, PageButton::Last].iter().copied());
+      buttons
+   }
+
+   /// The widest label of the button, which its layout is measured from.
+   pub fn widest_label(&self, page_count: usize) -> String {
+      match self {
+         PageButton::Slot(_) => page_count.to_string(),
+         _ => self.label(0, page_count),
+      }
+   }
+
+   pub fn label(&self, page: usize, page_count: usize) -> String {
+      match self {
+         PageButton::Size(size) => size.to_string(),
+         PageButton::First => String::from("«"),
+         PageButton::Previous => String::from("‹"),
+         PageButton::Slot(_) => (self.page(page, page_count) + 1).to_string(),
+         PageButton::Next => String::from("›"),
+         PageButton::Last => String::from("»"),
+      }
+   }
+
+   /// Returns the page this button leads to from `page`.
+   pub fn page(&self, page: usize, page_count: usize) -> usize {
+      let last = page_count.saturating_sub(1);
+      match self {
+         PageButton::Size(_) => page,
+         PageButton::First => 0,
+         PageButton::Previous => page.saturating_sub(1),
+         PageButton::Slot(slot) => page.saturating_sub(PAGE_SLOTS / 2).min(page_count.saturating_sub(PAGE_SLOTS)) + slot,
+         PageButton::Next => (page + 1).min(last),
+         PageButton::Last => last,
+      }
+   }
+
+   pub fn is_active(&self, page: usize, page_size: usize, page_count: usize) -> bool {
+      match self {
+         PageButton::Size(size) => *size == page_size,
+         PageButton::Slot(_) => self.page(page, page_count) == page,
+         _ => false,
+      }
+   }
+
+   pub fn is_enabled(&self, page: usize, page_size: usize, page_count: usize) -> bool {
+      match self {
+         PageButton::Size(size) => *size != page_size,
+         button => button.page(page, page_count) != page,
+      }
+   }
+}
+
+/// The text showing which rows the current page holds, e.g. `51–100 of 420`.
+pub(crate) fn range_text(page: usize, page_size: usize, row_count: usize) -> String {
+   if row_count == 0 {
+      return String::from("0 of 0");
+   }
+   let start = page * page_size;
+   format!("{}–{} of {}", start + 1, (start + page_size).min(row_count), row_count)
+}
+
+/// The pagination footer as drawn by the renderer.
+#[derive(Debug, Clone)]
+pub struct Footer {
+   pub bounds: Rectangle,
+   pub range: (String, Rectangle),
+   pub buttons: Vec<FooterButton>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FooterButton {
+   pub label: String,
+   pub bounds: Rectangle,
+   pub is_active: bool,
+   pub is_enabled: bool,
+}
@@ -0,0 +1,192 @@
+use iced_native::{scrollable, Layout, Point, Rectangle, Size, Vector};
+use std::ops::Range;
+
+pub(crate) const SCROLLBAR_WIDTH: f32 = 10.0;
+const MIN_SCROLLER_LENGTH: f32 = 20.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ScrollAxis {
+   Vertical,
+   Horizontal,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scrollbar {
+   pub bounds: Rectangle,
+   pub scroller: Rectangle,
+}
+
+impl Scrollbar {
+   /// Returns where the scroller was grabbed, relative to its length.
+   pub fn grab(&self, axis: ScrollAxis, cursor_position: Point) -> Option<f32> {
+      if !self.bounds.contains(cursor_position) {
+         None
+      } else if self.scroller.contains(cursor_position) {
+         Some(match axis {
+            ScrollAxis::Vertical => (cursor_position.y - self.scroller.y) / self.scroller.height,
+            ScrollAxis::Horizontal => (cursor_position.x - self.scroller.x) / self.scroller.width,
+         })
+      } else {
+         Some(0.5)
+      }
+   }
+
+   pub fn scroll_percentage(&self, axis: ScrollAxis, grabbed_at: f32, cursor_position: Point) -> f32 {
+      let percentage = match axis {
+         ScrollAxis::Vertical => (cursor_position.y - self.bounds.y - self.scroller.height * grabbed_at) / (self.bounds.height - self.scroller.height),
+         ScrollAxis::Horizontal => (cursor_position.x - self.bounds.x - self.scroller.width * grabbed_at) / (self.bounds.width - self.scroller.width),
+      };
+      percentage.clamp(0.0, 1.0)
+   }
+}
+
+/// The scrolled region of a table body: its visible bounds, the size of all
+/// its rows and the current offset. The first `pinned_width` units of the body
+/// never scroll horizontally.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Scroll {
+   pub bounds: Rectangle,
+   pub content: Size,
+   pub row_height: f32,
+   pub pinned_width: f32,
+   pub offset: Vector,
+}
+
+impl Scroll {
+   /// Reads the body node of a table layout, whose first child is the template row.
+   pub fn new(body: Layout<'_>, scrollable: &scrollable::State, scroll_x: f32, rows: usize, pinned_columns: usize) -> Self {
+      let bounds = body.bounds();
+      let (row_size, pinned_width) = body.children().next().map_or((Size::ZERO, 0.0), |row| {
+         (row.bounds().size(), row.children().take(pinned_columns).map(|cell| cell.bounds().width).sum())
+      });
+      let content = Size::new(row_size.width, row_size.height * rows as f32);
+      let content_bounds = Rectangle::new(bounds.position(), content);
+      let max_x = (content.width - bounds.width).max(0.0);
+
+      Self {
+         bounds,
+         content,
+         row_height: row_size.height,
+         pinned_width: pinned_width.min(bounds.width),
+         offset: Vector::new(scroll_x.max(0.0).min(max_x), scrollable.offset(bounds, content_bounds) as f32),
+      }
+   }
+
+   pub fn content_bounds(&self) -> Rectangle {
+      Rectangle::new(self.bounds.position(), self.content)
+   }
+
+   pub fn max_offset_x(&self) -> f32 {
+      (self.content.width - self.bounds.width).max(0.0)
+   }
+
+   pub fn is_scrollable(&self, axis: ScrollAxis) -> bool {
+      match axis {
+         ScrollAxis::Vertical => self.content.height > self.bounds.height,
+         ScrollAxis::Horizontal => self.content.width > self.bounds.width,
+      }
+   }
+
+   /// The part of the body that columns after the pinned ones are drawn in.
+   pub fn scrolled_bounds(&self, bounds: Rectangle) -> Rectangle {
+      Rectangle {
+         x: bounds.x + self.pinned_width,
+         width: (bounds.width - self.pinned_width).max(0.0),
+         ..bounds
+      }
+   }
+
+   pub fn pinned_bounds(&self, bounds: Rectangle) -> Rectangle {
+      Rectangle {
+         width: self.pinned_width,
+         ..bounds
+      }
+   }
+
+   /// Moves a cell of the template row to where it is shown for the given row.
+   pub fn cell(&self, cell: Rectangle, is_pinned: bool, row: usize) -> Rectangle {
+      Rectangle {
+         x: if is_pinned { cell.x } else { cell.x - self.offset.x },
+         y: cell.y + self.row_height * row as f32 - self.offset.y,
+         ..cell
+      }
+   }
+
+   /// Moves a header cell horizontally, headers never scroll vertically.
+   pub fn header_cell(&self, cell: Rectangle, is_pinned: bool) -> Rectangle {
+      Rectangle {
+         x: if is_pinned { cell.x } else { cell.x - self.offset.x },
+         ..cell
+      }
+   }
+
+   /// Returns the range of rows that intersect both the body and the viewport.
+   pub fn visible_rows(&self, viewport: &Rectangle, rows: usize) -> Range<usize> {
+      let top = self.bounds.y.max(viewport.y);
+      let bottom = (self.bounds.y + self.bounds.height).min(viewport.y + viewport.height);
+      if self.row_height <= 0.0 || bottom <= top {
+         return 0..0;
+      }
+      let first = ((top - self.bounds.y + self.offset.y) / self.row_height).floor() as usize;
+      let last = ((bottom - self.bounds.y + self.offset.y) / self.row_height).ceil() as usize;
+      first.min(rows)..last.min(rows)
+   }
+
+   pub fn row_at(&self, cursor_position: Point, rows: usize) -> Option<usize> {
+      if self.row_height <= 0.0 || !self.bounds.contains(cursor_position) {
+         return None;
+      }
+      let row = ((cursor_position.y - self.bounds.y + self.offset.y) / self.row_height) as usize;
+      if row < rows {
+         Some(row)
+      } else {
+         None
+      }
+   }
+
+   pub fn scrollbar(&self, axis: ScrollAxis) -> Option<Scrollbar> {
+      if !self.is_scrollable(axis) {
+         return None;
+      }
+
+      match axis {
+         ScrollAxis::Vertical => {
+            let bounds = Rectangle {
+               x: self.bounds.x + self.bounds.width - SCROLLBAR_WIDTH,
+               width: SCROLLBAR_WIDTH,
+               ..self.bounds
+            };
+            let length = (bounds.height * self.bounds.height / self.content.height).max(MIN_SCROLLER_LENGTH).min(bounds.height);
+            let max_offset = self.content.height - self.bounds.height;
+            Some(Scrollbar {
+               bounds,
+               scroller: Rectangle {
+                  y: bounds.y + (bounds.height - length) * self.offset.y / max_offset,
+                  height: length,
+                  ..bounds
+               },
+            })
+         }
+         ScrollAxis::Horizontal => {
+            let vertical_width = if self.is_scrollable(ScrollAxis::Vertical) { SCROLLBAR_WIDTH } else { 0.0 };
+            let bounds = Rectangle {
+               x: self.bounds.x + self.pinned_width,
+               y: self.bounds.y + self.bounds.height - SCROLLBAR_WIDTH,
+               width: (self.bounds.width - self.pinned_width - vertical_width).max(0.0),
+               height: SCROLLBAR_WIDTH,
+            };
+            let visible = self.bounds.width - self.pinned_width;
+            let hidden = self.content.width - self.pinned_width;
+            let length = (bounds.width * visible / hidden).max(MIN_SCROLLER_LENGTH).min(bounds.width);
+            Some(Scrollbar {
+               bounds,
+               scroller: Rectangle {
+                  x: bounds.x + (bounds.width - length) * self.offset.x / self.max_offset_x(),
+                  width: length,
+                  ..bounds
+               },
+            })
+         }
+      }
+   }
+}
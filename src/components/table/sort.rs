@@ -0,0 +1,98 @@
+use super::{
+   error::Result,
+   source::TableSource,
+   table::TableData,
+   table_column::TableOrder,
+};
+use serde_json::Value;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SortKey {
+   pub column: String,
+   pub order: TableOrder,
+}
+
+impl SortKey {
+   pub fn new(column: impl Into<String>, order: TableOrder) -> Self {
+      Self {
+         column: column.into(),
+         order,
+      }
+   }
+}
+
+/// Compares two field values by their JSON type instead of their string form.
+/// Values of different types are ordered bool < number < string < array < object,
+/// `null` is handled by the caller so that it always sorts last.
+pub fn compare_values(a: &Value, b: &Value) -> Ordering {
+   match (a, b) {
+      (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+      (Value::Number(a), Value::Number(b)) => {
+         if let (Some(a), Some(b)) = (a.as_i64(), b.as_i64()) {
+            a.cmp(&b)
+         } else if let (Some(a), Some(b)) = (a.as_u64(), b.as_u64()) {
+            a.cmp(&b)
+         } else {
+            let (a, b) = (a.as_f64().unwrap_or(f64::NAN), b.as_f64().unwrap_or(f64::NAN));
+            a.partial_cmp(&b).unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+         }
+      }
+      (Value::String(a), Value::String(b)) => a.cmp(b),
+      (Value::Array(a), Value::Array(b)) => a.iter().zip(b.iter()).map(|(a, b)| compare_values(a, b)).find(|ord| *ord != Ordering::Equal).unwrap_or_else(|| a.len().cmp(&b.len())),
+      (Value::Object(_), Value::Object(_)) => a.to_string().cmp(&b.to_string()),
+      (a, b) => type_rank(a).cmp(&type_rank(b)),
+   }
+}
+
+/// Compares two values for the given order, keeping `null` values last.
+pub fn compare_ordered(a: &Value, b: &Value, order: TableOrder) -> Ordering {
+   match (a.is_null(), b.is_null()) {
+      (true, true) => Ordering::Equal,
+      (true, false) => Ordering::Greater,
+      (false, true) => Ordering::Less,
+      (false, false) => match order {
+         TableOrder::Unordered => Ordering::Equal,
+         TableOrder::Ascending => compare_values(a, b),
+         TableOrder::Descending => compare_values(a, b).reverse(),
+      },
+   }
+}
+
+fn type_rank(value: &Value) -> u8 {
+   match value {
+      Value::Bool(_) => 0,
+      Value::Number(_) => 1,
+      Value::String(_) => 2,
+      Value::Array(_) => 3,
+      Value::Object(_) => 4,
+      Value::Null => 5,
+   }
+}
+
+/// Orders the record indices of `data` by `keys`, keeping the given order
+/// between equal records and when there is no key. Records not loaded yet go last.
+pub(crate) fn sort_indices<T: TableData, S: TableSource<T> + ?Sized>(data: &S, indices: &mut Vec<usize>, keys: &[SortKey]) -> Result<()> {
+   if keys.is_empty() {
+      return Ok(());
+   }
+
+   let (loaded, missing): (Vec<usize>, Vec<usize>) = indices.iter().partition(|&&idx| data.get(idx).is_some());
+   let mut records = loaded
+      .iter()
+      .filter_map(|&idx| data.get(idx).map(|record| (idx, record)))
+      .map(|(idx, record)| keys.iter().map(|key| record.get_field_value(&key.column)).collect::<Result<Vec<Value>>>().map(|values| (idx, values)))
+      .collect::<Result<Vec<_>>>()?;
+
+   records.sort_by(|(_, a), (_, b)| {
+      keys
+         .iter()
+         .zip(a.iter().zip(b.iter()))
+         .map(|(key, (a, b))| compare_ordered(a, b, key.order))
+         .find(|ord| *ord != Ordering::Equal)
+         .unwrap_or(Ordering::Equal)
+   });
+   *indices = records.into_iter().map(|(idx, _)| idx).chain(missing).collect();
+
+   Ok(())
+}
@@ -0,0 +1,197 @@
+use super::{
+   filter::Filter,
+   sort::SortKey,
+   table::TableData,
+};
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+
+/// The search, column filters and sort keys of a table, handed to its source.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Query {
+   pub text: String,
+   pub filters: Vec<(String, Filter)>,
+   pub sort_keys: Vec<SortKey>,
+}
+
+impl Query {
+   pub fn is_empty(&self) -> bool {
+      self.text.trim().is_empty() && self.filters.is_empty() && self.sort_keys.is_empty()
+   }
+}
+
+/// Where a table reads its records from. A `Vec` holds them all in memory,
+/// a [`LazySource`] loads them a block at a time.
+pub trait TableSource<T: TableData> {
+   /// The number of records, counting those not loaded yet.
+   fn len(&self) -> usize;
+
+   fn is_empty(&self) -> bool {
+      self.len() == 0
+   }
+
+   /// Returns the record at `row`, `None` while it is not loaded.
+   fn get(&self, row: usize) -> Option<&T>;
+
+   fn get_mut(&mut self, row: usize) -> Option<&mut T>;
+
+   /// Called with the records about to be shown. Returns the ranges of them
+   /// to load, which the table asks for with `Table::on_fetch`.
+   fn fetch(&mut self, _rows: Range<usize>) -> Vec<Range<usize>> {
+      Vec::new()
+   }
+
+   /// Lets the source search, filter and sort its records itself. Return `true` when it does,
+   /// the table then shows all of them in the order of the source, without groups or totals.
+   fn apply_query(&mut self, _query: &Query) -> bool {
+      false
+   }
+}
+
+impl<T: TableData> TableSource<T> for Vec<T> {
+   fn len(&self) -> usize {
+      self.as_slice().len()
+   }
+
+   fn get(&self, row: usize) -> Option<&T> {
+      self.as_slice().get(row)
+   }
+
+   fn get_mut(&mut self, row: usize) -> Option<&mut T> {
+      self.as_mut_slice().get_mut(row)
+   }
+}
+
+impl<T: TableData> TableSource<T> for [T] {
+   fn len(&self) -> usize {
+      <[T]>::len(self)
+   }
+
+   fn get(&self, row: usize) -> Option<&T> {
+      <[T]>::get(self, row)
+   }
+
+   fn get_mut(&mut self, row: usize) -> Option<&mut T> {
+      <[T]>::get_mut(self, row)
+   }
+}
+
+/// Records loaded on demand, for stores too large to hold in memory.
+///
+/// The table asks for the blocks of records it is about to show with `Table::on_fetch`,
+/// the application then loads them with a `Command`, applying [`LazySource::query`],
+/// and hands them over with [`LazySource::insert`]. Changing the query drops the loaded blocks.
+///
+/// The table can only ask for blocks while handling an event, so until the window gets one
+/// the rows show as placeholders. The application loads the first rows itself with
+/// [`TableSource::fetch`] when it creates the source, rather than waiting for input.
+#[derive(Debug, Clone)]
+pub struct LazySource<T> {
+   len: usize,
+   block_size: usize,
+   /// The records of each block, `None` for those not loaded yet.
+   blocks: HashMap<usize, Vec<Option<T>>>,
+   pending: HashSet<usize>,
+   query: Query,
+}
+
+impl<T: TableData> LazySource<T> {
+   /// Creates a source of `len` records, loaded `block_size` at a time.
+   pub fn new(len: usize, block_size: usize) -> Self {
+      Self {
+         len,
+         block_size: block_size.max(1),
+         blocks: HashMap::new(),
+         pending: HashSet::new(),
+         query: Query::default(),
+      }
+   }
+
+   /// Changes the number of records, e.g. once the store counted those matching the query.
+   pub fn set_len(&mut self, len: usize) {
+      self.len = len;
+   }
+
+   /// The query the records are loaded with.
+   pub fn query(&self) -> &Query {
+      &self.query
+   }
+
+   /// Stores records loaded from `start`, which is where a fetched range starts. Blocks may
+   /// arrive in any order. Returns `false` when some records fell past [`TableSource::len`] and
+   /// were dropped, the blocks they were fetched for are no longer pending and are fetched again.
+   pub fn insert(&mut self, start: usize, records: Vec<T>) -> bool {
+      let mut is_stored = true;
+      for (row, record) in (start..).zip(records) {
+         let block = row / self.block_size;
+         self.pending.remove(&block);
+         if row >= self.len {
+            is_stored = false;
+            continue;
+         }
+         let idx = row % self.block_size;
+         let stored = self.blocks.entry(block).or_default();
+         if stored.len() <= idx {
+            stored.resize_with(idx + 1, || None);
+         }
+         stored[idx] = Some(record);
+      }
+      is_stored
+   }
+
+   /// Drops every loaded record, they are fetched again once shown after `State::refresh`.
+   pub fn clear(&mut self) {
+      self.blocks.clear();
+      self.pending.clear();
+   }
+
+   pub fn is_loaded(&self, row: usize) -> bool {
+      TableSource::get(self, row).is_some()
+   }
+
+   /// Returns whether every record of `block` is loaded.
+   fn is_block_loaded(&self, block: usize) -> bool {
+      let start = block * self.block_size;
+      let end = (start + self.block_size).min(self.len);
+      self.blocks.get(&block).map_or(false, |stored| (start..end).all(|row| stored.get(row - start).map_or(false, Option::is_some)))
+   }
+}
+
+impl<T: TableData> TableSource<T> for LazySource<T> {
+   fn len(&self) -> usize {
+      self.len
+   }
+
+   fn get(&self, row: usize) -> Option<&T> {
+      self.blocks.get(&(row / self.block_size))?.get(row % self.block_size)?.as_ref()
+   }
+
+   fn get_mut(&mut self, row: usize) -> Option<&mut T> {
+      self.blocks.get_mut(&(row / self.block_size))?.get_mut(row % self.block_size)?.as_mut()
+   }
+
+   fn fetch(&mut self, rows: Range<usize>) -> Vec<Range<usize>> {
+      let end = rows.end.min(self.len);
+      if rows.start >= end {
+         return Vec::new();
+      }
+      let mut ranges = Vec::new();
+      for block in rows.start / self.block_size..=(end - 1) / self.block_size {
+         // a block missing records, e.g. some fell past a shorter length, is fetched again
+         if self.is_block_loaded(block) || !self.pending.insert(block) {
+            continue;
+         }
+         let start = block * self.block_size;
+         ranges.push(start..(start + self.block_size).min(self.len));
+      }
+      ranges
+   }
+
+   fn apply_query(&mut self, query: &Query) -> bool {
+      if *query != self.query {
+         self.query = query.clone();
+         self.clear();
+      }
+      true
+   }
+}
@@ -1,70 +1,562 @@
+mod events;
+mod layout;
+mod renderer;
+
 use super::{
-   error::Result,
-   table_column::{TableColumn, TableOptions, TableOrder},
+   cell::{self, CellKind, PROGRESS_BAR_WIDTH},
+   context_menu::{ContextMenu, MenuItem, MenuTarget, OpenMenu},
+   editor::{Editing, Editor},
+   tooltip::Tooltip,
+   error::{Result, TableError},
+   export::{self, ExportFormat},
+   filter::{self, Filter},
+   group::{self, Aggregate, Group, Line},
+   pagination::{self, Footer, FooterButton, PageButton},
+   scroll::{Scroll, ScrollAxis},
+   sort::{self, SortKey},
+   source::{Query, TableSource},
+   tree::{self, TreeData, TreeFns, TreeNode},
+   table_column::{ColumnLayout, TableColumn, TableOptions, TableOrder, TextOverflow},
 };
-use crate::styles::table::StyleSheet;
-use iced_graphics::Primitive;
+use crate::components::number_input;
+use crate::styles::table_row;
 use iced_native::{
-   event::{self, Event}, layout::{Limits, Node}, mouse, text, scrollable, container, 
-   Color, Element, Hasher, Clipboard, Vector, Point, Rectangle, Length, Size,
-   HorizontalAlignment, Layout, VerticalAlignment, Widget, Background, Container, Scrollable,
+   event::{self, Event}, layout::{Limits, Node}, keyboard, overlay, text, text_input, scrollable,
+   Element, Hasher, Clipboard, Point, Rectangle, Length, Size, Layout, Widget, TextInput,
 };
 use serde::{Serialize, de::DeserializeOwned};
 use serde_json::Value;
+use smart_default::SmartDefault;
+use std::cell::Cell;
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+pub use renderer::{Drawing, Renderer};
+
+const DOUBLE_CLICK_INTERVAL: Duration = Duration::from_millis(500);
+const VIRTUALIZED_SAMPLE_SIZE: usize = 100;
+const SCROLL_LINE_HEIGHT: f32 = 60.0;
+const MIN_COLUMN_WIDTH: f32 = 24.0;
+const RESIZE_HANDLE_WIDTH: f32 = 4.0;
+const DRAG_THRESHOLD: f32 = 5.0;
+
+/// What the header shows while the user resizes or moves columns.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnInteraction {
+   Idle,
+   /// The cursor is over the border of a column that can be resized.
+   ResizeHandle,
+   /// A column is resized, its right border is at the given x.
+   Resizing(f32),
+   /// A column is dragged and would be dropped on the border at the given x.
+   Moving(f32),
+}
+
+/// How clicks and keys select rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SmartDefault)]
+pub enum SelectionMode {
+   /// One row at a time.
+   #[default]
+   Single,
+   /// Ctrl-click toggles a row, Shift-click and Shift with navigation keys select
+   /// a range from the last clicked row, and Ctrl-A selects all rows.
+   Multiple,
+}
+
+/// Builds the content of a cell from its value, see [`Table::cell_view`].
+pub type CellView<'a, Message, Renderer> = Box<dyn Fn(&Value) -> Element<'a, Message, Renderer>>;
+
+/// Picks the style of a row from its record, see [`Table::row_style`].
+pub type RowStyle<T> = Box<dyn Fn(&T) -> Option<table_row::Style>>;
+
+/// Builds the message of a row from its index and record, see [`Table::on_select`].
+pub type OnRow<T, Message> = dyn Fn(usize, &T) -> Message;
+
+/// Builds the message of a sorted column from its name and order, see [`Table::on_sort`].
+pub type OnSort<Message> = dyn Fn(&str, TableOrder) -> Message;
+
+/// Builds the message of an edited cell from its row, column and new record, see [`Table::on_edit`].
+pub type OnEdit<T, Message> = dyn Fn(usize, &str, &T) -> Message;
+
+/// Builds the items of the context menu of a record.
+pub type RowMenu<T, Message> = Box<dyn Fn(&T) -> Vec<MenuItem<Message>>>;
+
+/// The items of the context menu of a header.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum HeaderAction {
+   Sort(TableOrder),
+   Hide,
+   ResetColumns,
+}
 
 pub trait TableData: 'static + Default + Clone + Ord + Serialize + DeserializeOwned {
    fn get_field_value(&self, field_name: &str) -> Result<Value>;
+
+   /// Writes an edited cell back to the record. Return an error to reject the value,
+   /// it is then shown below the cell editor.
+   fn set_field_value(&mut self, field_name: &str, _value: Value) -> Result<()> {
+      Err(TableError::ReadOnlyField(field_name.to_owned()))
+   }
+
+   /// The columns shown for this type by default. `#[derive(TableData)]` lists its
+   /// fields here, hand-written implementations usually build columns themselves.
+   fn columns() -> Vec<TableColumn> {
+      Vec::new()
+   }
 }
 
 #[derive(Debug, Clone, Default)]
 pub struct State {
    scrollable: scrollable::State,
+   selected_row: Option<usize>,
+   selection: BTreeSet<usize>,
+   selection_anchor: Option<usize>,
+   last_click: Option<(usize, Instant)>,
+   is_focused: bool,
+   sort_keys: Vec<SortKey>,
+   modifiers: keyboard::Modifiers,
+   scroll_x: f32,
+   scroller_grabbed_at: Option<(ScrollAxis, f32)>,
+   editing: Option<Editing>,
+   rows: Vec<usize>,
+   record_count: usize,
+   is_stale: bool,
+   /// Counts the refreshes of the rows, which change the records laid out, e.g. once edited.
+   revision: u64,
+   /// The records last fetched as shown, and the revision of the rows they were shown at.
+   fetched: Option<(Range<usize>, u64)>,
+   query: String,
+   filters: Vec<(String, Filter)>,
+   filter_inputs: HashMap<String, text_input::State>,
+   column_layout: ColumnLayout,
+   resizing: Option<ColumnResize>,
+   dragged_column: Option<ColumnDrag>,
+   is_paginated: bool,
+   page: usize,
+   page_size: usize,
+   group_by: Option<String>,
+   aggregates: Vec<(String, Aggregate)>,
+   groups: Vec<Group>,
+   totals: Vec<(String, String)>,
+   lines: Vec<Line>,
+   /// The groups hiding their records, keyed by their value.
+   collapsed_groups: HashSet<String>,
+   /// The keys of the expanded nodes of a tree table.
+   expanded_nodes: HashSet<String>,
+   /// The depth and state of each shown row of a tree table.
+   tree: HashMap<usize, TreeNode>,
+   is_tree: bool,
+   /// The text of the tooltip shown for the hovered header or cell, and the bounds it is shown below.
+   tooltip: Option<(String, Rectangle)>,
+   context_menu: Option<OpenMenu>,
+   /// The start and end of the page positions in view at the last layout, whose cell views are laid out.
+   shown: Cell<(usize, usize)>,
 }
+
+#[derive(Debug, Clone, Copy)]
+struct ColumnResize {
+   column: usize,
+   origin_x: f32,
+   width: f32,
+}
+
+/// A press on a header, which sorts its column when released in place
+/// and moves the column once dragged.
+#[derive(Debug, Clone, Copy)]
+struct ColumnDrag {
+   column: usize,
+   origin_x: f32,
+   is_moved: bool,
+}
+
 impl State {
    pub fn new() -> Self {
       Self::default()
    }
+
+   /// Returns the index in the table data of the current record, the last one clicked
+   /// or reached with the keyboard.
+   pub fn selected_row(&self) -> Option<usize> {
+      self.selected_row
+   }
+
+   /// Selects only the given record, or clears the selection.
+   pub fn select_row(&mut self, row: Option<usize>) {
+      self.selected_row = row;
+      self.selection = row.into_iter().collect();
+      self.selection_anchor = row;
+   }
+
+   /// Returns the indices in the table data of all selected records, in ascending order.
+   pub fn selected_rows(&self) -> impl Iterator<Item = usize> + '_ {
+      self.selection.iter().copied()
+   }
+
+   pub fn is_selected(&self, row: usize) -> bool {
+      self.selection.contains(&row)
+   }
+
+   /// Selects the given records, the last one becomes the current record.
+   pub fn set_selection(&mut self, rows: impl IntoIterator<Item = usize>) {
+      self.selection = rows.into_iter().collect();
+      self.selected_row = self.selection.iter().next_back().copied();
+      self.selection_anchor = self.selected_row;
+   }
+
+   /// Selects every row left by the filters, except those of collapsed groups.
+   pub fn select_all(&mut self) {
+      self.selection = self.shown_records().into_iter().collect();
+   }
+
+   pub fn clear_selection(&mut self) {
+      self.select_row(None);
+   }
+
+   pub fn is_focused(&self) -> bool {
+      self.is_focused
+   }
+
+   pub fn sort_keys(&self) -> &[SortKey] {
+      &self.sort_keys
+   }
+
+   pub fn set_sort_keys(&mut self, sort_keys: Vec<SortKey>) {
+      self.sort_keys = sort_keys;
+      self.is_stale = true;
+   }
+
+   /// Returns the current page, counted from 0.
+   pub fn page(&self) -> usize {
+      self.page.min(self.page_count() - 1)
+   }
+
+   pub fn set_page(&mut self, page: usize) {
+      self.page = page;
+   }
+
+   pub fn page_size(&self) -> usize {
+      self.page_size
+   }
+
+   /// Changes the number of rows per page, staying on the page that holds the first row shown.
+   pub fn set_page_size(&mut self, page_size: usize) {
+      let first_row = self.page() * self.page_size;
+      self.page_size = page_size.max(1);
+      self.page = first_row / self.page_size;
+   }
+
+   /// Returns the number of pages of a paginated table, at least one even when empty.
+   pub fn page_count(&self) -> usize {
+      if self.is_paginated && self.page_size > 0 {
+         ((self.lines.len() + self.page_size - 1) / self.page_size).max(1)
+      } else {
+         1
+      }
+   }
+
+   /// Returns the lines shown on the current page, all of them when not paginated.
+   pub(crate) fn page_lines(&self) -> &[Line] {
+      if self.is_paginated && self.page_size > 0 {
+         let start = (self.page() * self.page_size).min(self.lines.len());
+         &self.lines[start..(start + self.page_size).min(self.lines.len())]
+      } else {
+         &self.lines
+      }
+   }
+
+   /// Returns the records in the order shown, leaving out those of collapsed groups.
+   fn shown_records(&self) -> Vec<usize> {
+      self.lines.iter().filter_map(|line| match line {
+         Line::Record(row) => Some(*row),
+         Line::Group(_) => None,
+      }).collect()
+   }
+
+   pub fn group_by(&self) -> Option<&str> {
+      self.group_by.as_deref()
+   }
+
+   /// Groups the rows by the values of `column`, or ungroups them.
+   pub fn set_group_by(&mut self, column: Option<String>) {
+      self.group_by = column;
+      self.is_stale = true;
+   }
+
+   pub fn aggregate(&self, column: &str) -> Option<Aggregate> {
+      self.aggregates.iter().find(|(name, _)| name == column).map(|(_, aggregate)| *aggregate)
+   }
+
+   /// Summarizes `column` in the group headers and the totals row, replacing its previous aggregate.
+   pub fn set_aggregate(&mut self, column: impl Into<String>, aggregate: Aggregate) {
+      let column = column.into();
+      match self.aggregates.iter_mut().find(|(name, _)| *name == column) {
+         Some((_, previous)) => *previous = aggregate,
+         None => self.aggregates.push((column, aggregate)),
+      }
+      self.is_stale = true;
+   }
+
+   pub fn remove_aggregate(&mut self, column: &str) {
+      self.aggregates.retain(|(name, _)| name != column);
+      self.is_stale = true;
+   }
+
+   /// Returns whether the group of the records with `value` in the grouped column hides them.
+   pub fn is_group_collapsed(&self, value: &Value) -> bool {
+      self.collapsed_groups.contains(&group::group_key(value))
+   }
+
+   pub fn set_group_collapsed(&mut self, value: &Value, is_collapsed: bool) {
+      let key = group::group_key(value);
+      if is_collapsed {
+         self.collapsed_groups.insert(key);
+      } else {
+         self.collapsed_groups.remove(&key);
+      }
+      self.lines = group::lines(&self.rows, &self.groups, &self.collapsed_groups);
+   }
+
+   /// Whether the node of a tree table with the given key shows its children.
+   pub fn is_expanded(&self, key: &str) -> bool {
+      self.expanded_nodes.contains(key)
+   }
+
+   pub fn set_expanded(&mut self, key: impl Into<String>, is_expanded: bool) {
+      let key = key.into();
+      if is_expanded {
+         self.expanded_nodes.insert(key);
+      } else {
+         self.expanded_nodes.remove(&key);
+      }
+      self.is_stale = true;
+   }
+
+   pub fn collapse_all(&mut self) {
+      self.expanded_nodes.clear();
+      self.is_stale = true;
+   }
+
+   /// Returns the number of rows left once filtered.
+   pub fn row_count(&self) -> usize {
+      self.rows.len()
+   }
+
+   /// Returns the indices of the records shown, in the order they are shown.
+   pub fn rows(&self) -> &[usize] {
+      &self.rows
+   }
+
+   pub fn query(&self) -> &str {
+      &self.query
+   }
+
+   /// Shows only the records with the query in the text of any of their columns.
+   pub fn set_query(&mut self, query: impl Into<String>) {
+      self.query = query.into();
+      self.filters_changed();
+   }
+
+   pub fn filter(&self, column: &str) -> Option<&Filter> {
+      self.filters.iter().find(|(name, _)| name == column).map(|(_, filter)| filter)
+   }
+
+   /// Shows only the records whose value of `column` matches `filter`, replacing its previous filter.
+   pub fn set_filter(&mut self, column: impl Into<String>, filter: Filter) {
+      let column = column.into();
+      match self.filters.iter_mut().find(|(name, _)| *name == column) {
+         Some((_, previous)) => *previous = filter,
+         None => self.filters.push((column, filter)),
+      }
+      self.filters_changed();
+   }
+
+   pub fn remove_filter(&mut self, column: &str) {
+      self.filters.retain(|(name, _)| name != column);
+      self.filters_changed();
+   }
+
+   /// Removes the query and all column filters.
+   pub fn clear_filters(&mut self) {
+      self.query.clear();
+      self.filters.clear();
+      self.filters_changed();
+   }
+
+   /// Exports the rows left by the filters, in the order shown, limited to the shown columns
+   /// and keyed by their label. `columns` are the ones the table is built with.
+   pub fn export<T: TableData, S: TableSource<T> + ?Sized>(&self, data: &S, columns: Vec<TableColumn>, format: ExportFormat) -> Result<String> {
+      let (columns, _) = self.column_layout.arrange(columns);
+      export::export(data, &self.rows, &columns, format)
+   }
+
+   /// Filters the rows again on the next view, which then starts from the first page.
+   fn filters_changed(&mut self) {
+      self.is_stale = true;
+      self.page = 0;
+   }
+
+   pub fn column_layout(&self) -> &ColumnLayout {
+      &self.column_layout
+   }
+
+   /// Restores the widths, order and visibility of columns, e.g. saved from a previous session.
+   pub fn set_column_layout(&mut self, column_layout: ColumnLayout) {
+      self.column_layout = column_layout;
+      self.is_stale = true;
+   }
+
+   pub fn is_column_hidden(&self, column: &str) -> bool {
+      self.column_layout.is_hidden(column)
+   }
+
+   pub fn set_column_hidden(&mut self, column: &str, is_hidden: bool) {
+      self.column_layout.set_hidden(column, is_hidden);
+      // the query only matches shown columns
+      self.is_stale = true;
+   }
+
+   /// Filters and sorts the rows again on the next view, for records changed in place.
+   pub fn refresh(&mut self) {
+      self.is_stale = true;
+   }
+
+   /// Returns the row and the column name of the cell being edited.
+   pub fn editing_cell(&self) -> Option<(usize, &str)> {
+      self.editing.as_ref().filter(|editing| !editing.is_closed).map(|editing| (editing.row, editing.column.as_str()))
+   }
+
+   pub fn cancel_editing(&mut self) {
+      self.editing = None;
+   }
+
+   fn sort_order(&self, column: &str) -> TableOrder {
+      self.sort_keys.iter().find(|key| key.column == column).map_or(TableOrder::Unordered, |key| key.order)
+   }
 }
 
-pub struct Table<'a, T, Renderer>
+pub struct Table<'a, T, Message, Renderer>
 where
    T: TableData,
    Renderer: self::Renderer,
 {
    state: &'a mut State,
    columns: Vec<TableColumn>,
-   data: &'a mut Vec<T>,
+   column_order: Vec<String>,
+   data: &'a mut dyn TableSource<T>,
    option: Option<TableOptions>,
-   selected_row: Option<usize>,
+   on_select: Option<Box<OnRow<T, Message>>>,
+   on_sort: Option<Box<OnSort<Message>>>,
+   on_activate: Option<Box<OnRow<T, Message>>>,
+   on_edit: Option<Box<OnEdit<T, Message>>>,
+   on_selection_change: Option<Box<dyn Fn(Vec<usize>) -> Message>>,
+   on_column_layout_change: Option<Box<dyn Fn(ColumnLayout) -> Message>>,
+   on_page_change: Option<Box<dyn Fn(usize) -> Message>>,
+   on_copy: Option<Box<dyn Fn(String) -> Message>>,
+   on_fetch: Option<Box<dyn Fn(Range<usize>) -> Message>>,
+   on_expand: Option<Box<OnRow<T, Message>>>,
+   tree: Option<TreeFns<T>>,
+   /// The rows filtered and sorted by the refresh of this build, and whether the source
+   /// queried them, arranged again rather than refreshed once the table turns into a tree.
+   refreshed: Option<(Vec<usize>, bool)>,
+   cell_views: HashMap<String, CellView<'a, Message, Renderer>>,
+   row_style: Option<RowStyle<T>>,
+   row_menu: Option<RowMenu<T, Message>>,
+   header_menu: bool,
    width: Length,
+   height: Length,
+   max_height: Option<u32>,
+   row_height: Option<u16>,
+   pinned_columns: usize,
+   is_virtualized: bool,
    padding: u16,
    header_spacing: u16,
    column_max_width: Option<f32>,
    text_size: Option<u16>,
+   header_filters: bool,
+   selection_mode: SelectionMode,
+   page_sizes: Vec<usize>,
+   is_resizable: bool,
+   is_reorderable: bool,
+   totals_row: bool,
    font: Renderer::Font,
-   style: Renderer::Style,
+   style: <Renderer as self::Renderer>::Style,
 }
 
-impl<'a, T, Renderer> Table<'a, T, Renderer>
+impl<'a, T, Message, Renderer> Table<'a, T, Message, Renderer>
 where
    T: TableData,
    Renderer: self::Renderer,
 {
-   pub fn new(state: &'a mut State, columns: Vec<TableColumn>, data: &'a mut Vec<T>) -> Self {
-      Table {
+   /// Shows the records of `data`, a `Vec` or any other [`TableSource`].
+   pub fn new(state: &'a mut State, columns: Vec<TableColumn>, data: &'a mut dyn TableSource<T>) -> Self {
+      if state.selected_row.map_or(false, |row| row >= data.len()) {
+         state.selected_row = None;
+      }
+      let len = data.len();
+      state.selection = state.selection.range(..len).copied().collect();
+      if state.editing.as_ref().map_or(false, |editing| editing.is_closed || editing.row >= data.len()) {
+         // an edited record may no longer match the filters or keep its place
+         state.editing = None;
+         state.is_stale = true;
+      }
+      if let Some(MenuTarget::Row(row)) = state.context_menu.map(|menu| menu.target) {
+         if row >= data.len() {
+            state.context_menu = None;
+         }
+      }
+      let is_stale = state.is_stale || state.record_count != data.len();
+      state.is_paginated = false;
+      let (columns, column_order) = state.column_layout.arrange(columns);
+
+      let mut table = Table {
          state,
          columns,
+         column_order,
          data,
          option: None,
-         selected_row: None,
+         on_select: None,
+         on_sort: None,
+         on_activate: None,
+         on_edit: None,
+         on_selection_change: None,
+         on_column_layout_change: None,
+         on_page_change: None,
+         on_copy: None,
+         on_fetch: None,
+         on_expand: None,
+         tree: None,
+         refreshed: None,
+         cell_views: HashMap::new(),
+         row_style: None,
+         row_menu: None,
+         header_menu: false,
          width: Length::Shrink,
-         padding: Renderer::DEFAULT_PADDING,
+         height: Length::Shrink,
+         max_height: None,
+         row_height: None,
+         pinned_columns: 0,
+         is_virtualized: false,
+         padding: <Renderer as self::Renderer>::DEFAULT_PADDING,
          header_spacing: Renderer::DEFAULT_HEADER_SPACING,
          column_max_width: None,
          text_size: None,
+         header_filters: false,
+         selection_mode: SelectionMode::Single,
+         page_sizes: Vec::new(),
+         is_resizable: true,
+         is_reorderable: true,
+         totals_row: false,
          font: Renderer::Font::default(),
-         style: Renderer::Style::default(),
+         style: <Renderer as self::Renderer>::Style::default(),
+      };
+      if is_stale {
+         table.refresh();
       }
+      for column in table.columns.iter_mut() {
+         column.order = table.state.sort_order(&column.name);
+      }
+      table
    }
 
    pub fn option(mut self, option: TableOptions) -> Self {
@@ -72,11 +564,168 @@ where
       self
    }
 
+   pub fn on_select<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(usize, &T) -> Message,
+   {
+      self.on_select = Some(Box::new(f));
+      self
+   }
+
+   pub fn on_sort<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(&str, TableOrder) -> Message,
+   {
+      self.on_sort = Some(Box::new(f));
+      self
+   }
+
+   pub fn on_activate<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(usize, &T) -> Message,
+   {
+      self.on_activate = Some(Box::new(f));
+      self
+   }
+
+   /// Called with the row, the column name and the updated record once an edited cell is committed.
+   pub fn on_edit<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(usize, &str, &T) -> Message,
+   {
+      self.on_edit = Some(Box::new(f));
+      self
+   }
+
+   /// Called with the records a lazy source has to load before they can be shown,
+   /// see [`LazySource`](super::LazySource). A widget only publishes messages while handling
+   /// an event, so the records shown are asked for on the next event the table gets, e.g. the
+   /// cursor moving or a key being released, not as soon as they are laid out.
+   pub fn on_fetch<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(Range<usize>) -> Message,
+   {
+      self.on_fetch = Some(Box::new(f));
+      self
+   }
+
+   /// Called with the row and the record of a tree node once it is expanded, to load its children.
+   pub fn on_expand<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(usize, &T) -> Message,
+   {
+      self.on_expand = Some(Box::new(f));
+      self
+   }
+
+   /// Called with the indices in the table data of all selected records whenever the selection changes.
+   pub fn on_selection_change<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(Vec<usize>) -> Message,
+   {
+      self.on_selection_change = Some(Box::new(f));
+      self
+   }
+
+   /// Called with the new column layout once the user resized or moved a column, to save it.
+   pub fn on_column_layout_change<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(ColumnLayout) -> Message,
+   {
+      self.on_column_layout_change = Some(Box::new(f));
+      self
+   }
+
+   /// Called with the new page, counted from 0, once the user moved to another one.
+   pub fn on_page_change<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(usize) -> Message,
+   {
+      self.on_page_change = Some(Box::new(f));
+      self
+   }
+
+   /// Called with the selected rows as tab-separated values when the user presses Ctrl-C.
+   /// The clipboard handed to widgets can only be read, so the application writes it.
+   pub fn on_copy<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(String) -> Message,
+   {
+      self.on_copy = Some(Box::new(f));
+      self
+   }
+
+   /// Draws the cells of `column` with the element returned by `view`, instead of its `CellKind`.
+   /// The element of each row in view is laid out and receives the events of its own cell.
+   pub fn cell_view<F>(mut self, column: &str, view: F) -> Self
+   where
+      F: 'static + Fn(&Value) -> Element<'a, Message, Renderer>,
+   {
+      self.cell_views.insert(column.to_string(), Box::new(view));
+      self
+   }
+
+   /// Styles the rows whose record `f` returns a style for, e.g. to grey out archived ones.
+   /// Selected and hovered rows are still highlighted over it.
+   pub fn row_style<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(&T) -> Option<table_row::Style>,
+   {
+      self.row_style = Some(Box::new(f));
+      self
+   }
+
+   /// Opens a menu with the items returned by `f` when a record is right-clicked,
+   /// the record is selected first.
+   pub fn row_context_menu<F>(mut self, f: F) -> Self
+   where
+      F: 'static + Fn(&T) -> Vec<MenuItem<Message>>,
+   {
+      self.row_menu = Some(Box::new(f));
+      self
+   }
+
+   /// Opens a menu to sort, hide or reset the columns when a header is right-clicked.
+   pub fn header_context_menu(mut self, header_menu: bool) -> Self {
+      self.header_menu = header_menu;
+      self
+   }
+
    pub fn width(mut self, width: Length) -> Self {
       self.width = width;
       self
    }
 
+   pub fn height(mut self, height: Length) -> Self {
+      self.height = height;
+      self
+   }
+
+   /// Limits the height of the table, the rows scroll below the header once they exceed it.
+   pub fn target_height(mut self, target_height: u32) -> Self {
+      self.max_height = Some(target_height);
+      self
+   }
+
+   /// Keeps the first `count` columns in place while scrolling horizontally.
+   pub fn pinned_columns(mut self, count: usize) -> Self {
+      self.pinned_columns = count;
+      self
+   }
+
+   pub fn row_height(mut self, row_height: u16) -> Self {
+      self.row_height = Some(row_height);
+      self
+   }
+
+   /// Measures only a sample of the records and draws only the rows inside the viewport.
+   /// Column widths are derived from the sample, so combine it with `column_max_width`
+   /// or `row_height` when the records vary a lot.
+   pub fn virtualized(mut self, is_virtualized: bool) -> Self {
+      self.is_virtualized = is_virtualized;
+      self
+   }
+
    pub fn padding(mut self, padding: u16) -> Self {
       self.padding = padding;
       self
@@ -92,6 +741,52 @@ where
       self
    }
 
+   /// Shows a text input below each header label that filters its column.
+   pub fn header_filters(mut self, header_filters: bool) -> Self {
+      self.header_filters = header_filters;
+      self
+   }
+
+   pub fn selection_mode(mut self, selection_mode: SelectionMode) -> Self {
+      self.selection_mode = selection_mode;
+      self
+   }
+
+   /// Shows the rows page by page instead of scrolling through all of them, with a footer
+   /// to move between pages. Pages hold `page_size` rows until another size is set in the state.
+   pub fn paginated(self, page_size: usize) -> Self {
+      self.state.is_paginated = true;
+      if self.state.page_size == 0 {
+         self.state.page_size = page_size.max(1);
+      }
+      self
+   }
+
+   /// The page sizes the user can pick from in the footer of a paginated table.
+   pub fn page_sizes(mut self, page_sizes: Vec<usize>) -> Self {
+      self.page_sizes = page_sizes;
+      self
+   }
+
+   /// Shows a row below the body with the aggregates of all rows left by the filters,
+   /// see [`State::set_aggregate`].
+   pub fn totals_row(mut self, totals_row: bool) -> Self {
+      self.totals_row = totals_row;
+      self
+   }
+
+   /// Lets the user resize columns by dragging the right border of their header.
+   pub fn resizable_columns(mut self, is_resizable: bool) -> Self {
+      self.is_resizable = is_resizable;
+      self
+   }
+
+   /// Lets the user move columns by dragging their header.
+   pub fn reorderable_columns(mut self, is_reorderable: bool) -> Self {
+      self.is_reorderable = is_reorderable;
+      self
+   }
+
    pub fn text_size(mut self, text_size: u16) -> Self {
       self.text_size = Some(text_size);
       self
@@ -102,11 +797,22 @@ where
       self
    }
 
-   pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
+   pub fn style(mut self, style: impl Into<<Renderer as self::Renderer>::Style>) -> Self {
       self.style = style.into();
       self
    }
 
+   /// Returns the number of rows left once filtered, up to date with this table unlike
+   /// `State::row_count` read before building it.
+   pub fn row_count(&self) -> usize {
+      self.state.rows.len()
+   }
+
+   /// Exports the rows left by the filters, see [`State::export`].
+   pub fn export(&self, format: ExportFormat) -> Result<String> {
+      export::export(&*self.data, &self.state.rows, &self.columns, format)
+   }
+
    fn is_orderable(&self) -> bool {
       if let Some(option) = &self.option {
          option.orderable
@@ -115,111 +821,118 @@ where
       }
    }
 
-   fn trigger_sort_column(&mut self, idx: usize) {
-      use TableOrder::*;
+   fn scroll(&self, layout: Layout<'_>) -> Scroll {
+      Scroll::new(layout.children().nth(2).unwrap(), &self.state.scrollable, self.state.scroll_x, self.state.page_lines().len(), self.pinned_columns)
+   }
+
+   /// Filters and sorts the record indices shown by the table, leaving the data untouched.
+   fn refresh(&mut self) {
+      let query = Query {
+         text: self.state.query.clone(),
+         filters: self.state.filters.clone(),
+         sort_keys: self.state.sort_keys.clone(),
+      };
+      // a source applying the query itself is shown as it is
+      let is_queried = self.data.apply_query(&query);
+      let mut rows: Vec<usize> = if is_queried {
+         (0..self.data.len()).collect()
+      } else {
+         filter::filter_indices(&*self.data, &self.columns, &self.state.query, &self.state.filters)
+      };
+      if !is_queried && sort::sort_indices(&*self.data, &mut rows, &self.state.sort_keys).is_err() {
+         let data = &self.data;
+         self.state.sort_keys.retain(|key| data.get(0).map_or(true, |record| record.get_field_value(&key.column).is_ok()));
+         let _ = sort::sort_indices(&*self.data, &mut rows, &self.state.sort_keys);
+      }
+      self.arrange(&rows, is_queried);
+      self.refreshed = Some((rows, is_queried));
+   }
+
+   /// Lists the filtered and sorted rows as a tree or in groups, with the lines shown.
+   fn arrange(&mut self, sorted: &[usize], is_queried: bool) {
+      let mut rows = sorted.to_vec();
+      self.state.tree = match &self.tree {
+         Some(tree) => {
+            let (shown, nodes) = tree::flatten(&*self.data, &rows, tree, &self.state.expanded_nodes);
+            rows = shown;
+            nodes
+         }
+         None => HashMap::new(),
+      };
+      self.state.is_tree = self.tree.is_some();
+
+      if !self.state.selection.is_empty() || self.state.selected_row.is_some() {
+         let shown: HashSet<usize> = rows.iter().copied().collect();
+         self.state.selection = self.state.selection.iter().copied().filter(|row| shown.contains(row)).collect();
+         if self.state.selected_row.map_or(false, |row| !shown.contains(&row)) {
+            self.state.selected_row = None;
+            self.state.last_click = None;
+         }
+      }
+      let columns = &self.columns;
+      self.state.groups = match &self.state.group_by {
+         Some(column) if !is_queried && self.tree.is_none() => group::group_rows(&*self.data, &mut rows, column, columns, &self.state.aggregates),
+         _ => Vec::new(),
+      };
+      self.state.totals = if is_queried { Vec::new() } else { group::aggregate_texts(&*self.data, &rows, columns, &self.state.aggregates) };
+      self.state.lines = group::lines(&rows, &self.state.groups, &self.state.collapsed_groups);
+      self.state.rows = rows;
+      self.state.record_count = self.data.len();
+      self.state.is_stale = false;
+      self.state.revision = self.state.revision.wrapping_add(1);
+   }
+
+   fn filter_text(&self, column: &str) -> String {
+      match self.state.filter(column) {
+         Some(Filter::Contains(text)) => text.clone(),
+         _ => String::new(),
+      }
+   }
+
+   fn filter_input<'b>(state: &'b mut text_input::State, value: &str, text_size: u16, padding: u16, font: Renderer::Font) -> TextInput<'b, String, Renderer> {
+      TextInput::new(state, "Filter", value, |text| text).size(text_size).padding(padding / 2).font(font)
+   }
 
-      for (i, column) in self.columns.iter_mut().enumerate() {
-         let TableColumn{name, order, ..} = column;
-         if i != idx {
-            *order = Unordered;
-         } else {
-            *order = order.toggle();
-            match order {
-               Unordered => self.data.sort(),
-               Ascending => self.data.sort_by_cached_key(|x| x.get_field_value(&name).unwrap().to_string()),
-               Descending => self.data.sort_by_cached_key(|x| std::cmp::Reverse(x.get_field_value(&name).unwrap().to_string())),
+}
+
+impl<'a, T, Message, Renderer> Table<'a, T, Message, Renderer>
+where
+   T: TreeData,
+   Renderer: self::Renderer,
+{
+   /// Shows the records as a tree, each node below its parent once expanded, with a chevron and
+   /// indented by depth in the first column. A tree table shows no groups.
+   pub fn tree(mut self) -> Self {
+      self.tree = Some(TreeFns::new());
+      // the rows are listed flat until arranged as a tree, from those already sorted when refreshed by `new`
+      if !self.state.is_tree {
+         match self.refreshed.take() {
+            Some((rows, is_queried)) => {
+               self.arrange(&rows, is_queried);
+               self.refreshed = Some((rows, is_queried));
             }
+            None => self.refresh(),
          }
       }
+      self
    }
 }
 
-impl<'a, Message, Renderer, T> Widget<Message, Renderer> for Table<'a, T, Renderer>
+impl<'a, T, Message, Renderer> Widget<Message, Renderer> for Table<'a, T, Message, Renderer>
 where
    T: TableData,
-   Renderer: self::Renderer,
+   Renderer: self::Renderer + number_input::Renderer,
 {
    fn width(&self) -> Length {
       self.width
    }
 
    fn height(&self) -> Length {
-      Length::Shrink
+      self.height
    }
 
    fn layout(&self, renderer: &Renderer, limits: &Limits) -> Node {
-      let padding = f32::from(self.padding);
-      let text_size = self.text_size.unwrap_or(renderer.default_size());
-      let limits = limits.width(self.width).height(Length::Shrink);
-      let mut max_cols_size: Vec<Size> = Vec::with_capacity(self.columns.len());
-
-      for column in self.columns.iter() {
-         let (width, height) = renderer.measure(&formatted_sortable_column(column.to_string(), column.order), text_size, self.font, Size::new(f32::INFINITY, f32::INFINITY),);
-         let size = {
-            let intrinsic = Size::new(width+f32::from(text_size), height);
-            // limits.resolve(intrinsic).pad(padding)
-            intrinsic.pad(padding)
-         };
-         max_cols_size.push(size);
-      }
-      for record in self.data.iter() {
-         self.columns.iter()
-         .map(|c| c.name.as_str())
-         .map(|name| record.get_field_value(name))
-         .filter_map(|h| h.ok())
-         .enumerate()
-         .for_each(|(idx, value)| {
-            let (width, height) = renderer.measure(&serde_json::to_string(&value).unwrap(), text_size, self.font, Size::new(f32::INFINITY, f32::INFINITY),);
-            let size = {
-               let intrinsic = Size::new(if let Some(max_width) = self.column_max_width {width.min(max_width)} else {width}, height);
-               // limits.resolve(intrinsic).pad(padding)
-               intrinsic.pad(padding)
-            };
-            if let Some(max_size) = max_cols_size.get_mut(idx) {
-               max_size.width = max_size.width.max(size.width);
-               max_size.height = max_size.height.max(size.height);
-            }
-         });
-      }
-
-      let mut header_nodes = Vec::new();
-      let mut header_size = Size::ZERO;
-      for size in max_cols_size.iter() {
-         let mut node = Node::new(*size);
-         node.move_to(Point::new(header_size.width, 0.0));
-         header_size.width += size.width;
-         header_size.height = header_size.height.max(size.height);
-         header_nodes.push(node);
-      }
-      let mut header = Node::with_children(header_size, header_nodes);
-      header.move_to(Point::new(1.0, 1.0));
-
-      let mut body_nodes = Vec::with_capacity(self.data.len());
-      let mut table_size = Size::new(0.0, header_size.height);
-      for _ in self.data.iter() {
-         let mut record_nodes = Vec::with_capacity(self.columns.len());
-         let mut record_size = Size::ZERO;
-         for size in max_cols_size.iter() {
-            let mut cell = Node::new(*size);
-            cell.move_to(Point::new(record_size.width, table_size.height));
-            record_size.width += size.width;
-            record_size.height = record_size.height.max(size.height);
-            record_nodes.push(cell);
-         }
-         let record = Node::with_children(record_size, record_nodes);
-         // record.move_to(Point::new(1.0, table_size.height));
-         table_size.width = table_size.width.max(record_size.width);
-         table_size.height += record_size.height;
-         body_nodes.push(record);
-      }
-      let body = Node::with_children(table_size, body_nodes);
-      // body.move_to(Point::new(0.0, header_size.height));
-      table_size.width = header_size.width.max(table_size.width)+2.0;
-
-      let mut divider = Node::new(Size::new(table_size.width, 1.0));
-      divider.move_to(Point::new(0.0, header_size.height));
-
-      Node::with_children(table_size, vec![header, divider, body])
+      self.layout_table(renderer, limits)
    }
 
    fn draw(
@@ -230,20 +943,39 @@ where
       cursor_position: Point,
       viewport: &Rectangle,
    ) -> Renderer::Output {
-      self::Renderer::draw(
-         renderer,
-         defaults,
-         layout,
-         cursor_position,
-         viewport,
-         &self.columns,
-         &self.data,
-         self.is_orderable(),
-         self.text_size.unwrap_or(renderer.default_size()),
-         self.padding,
-         self.font,
-         &self.style,
-      )
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let scroll = self.scroll(layout);
+      let filter_inputs = self
+         .columns
+         .iter()
+         .zip(layout.children().next().unwrap().children())
+         .enumerate()
+         .filter_map(|(idx, (column, cell))| {
+            let input_layout = cell.children().next()?;
+            let translation = scroll.header_cell(cell.bounds(), idx < self.pinned_columns).position() - cell.bounds().position();
+            let mut state = self.state.filter_inputs.get(&column.name).cloned().unwrap_or_default();
+            let input = Self::filter_input(&mut state, &self.filter_text(&column.name), text_size, self.padding, self.font);
+            Some(Widget::<String, Renderer>::draw(&input, renderer, defaults, input_layout, cursor_position - translation, viewport))
+         })
+         .collect();
+
+      let table = Drawing {
+         columns: &self.columns,
+         data: &*self.data,
+         cell_views: &self.cell_views,
+         row_style: self.row_style.as_ref(),
+         filter_inputs,
+         footer: self.footer(layout),
+         column_interaction: self.column_interaction(layout, &scroll, cursor_position),
+         state: self.state,
+         pinned_columns: self.pinned_columns,
+         is_orderable: self.is_orderable(),
+         text_size,
+         padding: self.padding,
+         font: self.font,
+         style: &self.style,
+      };
+      self::Renderer::draw(renderer, defaults, layout, cursor_position, viewport, table)
    }
 
    fn hash_layout(&self, state: &mut Hasher) {
@@ -251,256 +983,164 @@ where
       struct Marker;
       std::any::TypeId::of::<Marker>().hash(state);
 
-      self
-         .columns
-         .iter()
-         .map(|column| (column.to_string(), &column.name))
-         .zip(self.data.clone())
-         .for_each(|((label, name), record)| {
-            label.hash(state);
-            format!("{}", record.get_field_value(&name).unwrap()).hash(state);
-         });
-   }
-
-   fn on_event(&mut self, event: Event, layout: Layout<'_>, cursor_position: Point, _messages: &mut Vec<Message>, _renderer: &Renderer, _clipboard: Option<&dyn Clipboard>) -> event::Status {
-      let mouse_over = layout.bounds().contains(cursor_position);
-      let mut children = layout.children();
-      let header_layout = children.next().unwrap();
-      let body_layout = children.next().unwrap();
-      let mut event_status = event::Status::Ignored;
-
-      if mouse_over {
-         event_status = event::Status::Captured;
-         match event {
-            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-               if header_layout.bounds().contains(cursor_position) && self.is_orderable() {
-                  let idx = self.columns.iter().enumerate().zip(header_layout.children()).find(|((_, _), layout)| layout.bounds().contains(cursor_position))
-                     .map(|((idx, _), _)| idx).unwrap();
-                  self.trigger_sort_column(idx);
-               } else if body_layout.bounds().contains(cursor_position) {
-                  self.selected_row = self.data.iter().enumerate().zip(body_layout.children())
-                     .find(|((_, _), layout)| layout.bounds().contains(cursor_position)).map(|((idx, _), _)| idx);
-               }
-            }
-            _ => {}
-         }
+      self.width.hash(state);
+      self.height.hash(state);
+      self.max_height.hash(state);
+      self.row_height.hash(state);
+      self.padding.hash(state);
+      self.text_size.hash(state);
+      self.column_max_width.map(f32::to_bits).hash(state);
+      self.data.len().hash(state);
+      self.state.rows.len().hash(state);
+      self.state.page_lines().len().hash(state);
+      self.state.is_paginated.hash(state);
+      self.state.tree.values().map(|node| node.depth).max().hash(state);
+      self.totals_row.hash(state);
+      self.page_sizes.hash(state);
+      self.header_filters.hash(state);
+      // the cell views are laid out for the rows in view, which change when scrolled
+      if !self.cell_views.is_empty() {
+         let unbounded = Rectangle { height: f32::INFINITY, ..Rectangle::default() };
+         self.state.scrollable.offset(Rectangle::default(), unbounded).hash(state);
       }
+      self.columns.iter().for_each(|column| {
+         column.to_string().hash(state);
+         column.name.hash(state);
+         self.state.column_layout.width(&column.name).map(f32::to_bits).hash(state);
+         column.width.hash(state);
+         column.min_width.map(f32::to_bits).hash(state);
+         column.max_width.map(f32::to_bits).hash(state);
+         column.overflow.hash(state);
+      });
+      // the records are measured again once refreshed, and as the sampled ones get loaded
+      self.state.revision.hash(state);
+      if self.is_virtualized {
+         self.measured_records().count().hash(state);
+      }
+   }
 
-      event_status
+   fn on_event(&mut self, event: Event, layout: Layout<'_>, cursor_position: Point, messages: &mut Vec<Message>, renderer: &Renderer, clipboard: Option<&dyn Clipboard>) -> event::Status {
+      self.handle_event(event, layout, cursor_position, messages, renderer, clipboard)
    }
-}
 
-// struct TableContainer<'a, Message, Renderer: self::Renderer> {
-//    container: Container<'a, Message, Renderer>,
-//    width: Length,
-//    target_height: f32,
-// }
-
-// impl<'a, Message, Renderer> TableContainer<'a, Message, Renderer>
-// where 
-//    Message: 'a,
-//    Renderer: 'a + self::Renderer
-// {
-//    pub fn new<T>(table: Table<'a, T, Renderer>, target_height: f32) -> Self
-//    where T: TableData {
-//       let Table {
-//          state,
-//          option,
-//          width,
-//          padding,
-//          font,
-//          text_size,
-//          style,
-//          ..
-//       } = table;
-
-//       let container = Container::new(Scrollable::new(&mut state.scrollable).push(child)).padding(1);
-//       Self {
-//          container,
-//          width,
-//          target_height,
-//      }
-//    }
-// }
-
-pub trait Renderer: text::Renderer {
-   type Style: Default;
-   const DEFAULT_PADDING: u16;
-   const DEFAULT_HEADER_SPACING: u16;
-
-   fn draw<T: TableData>(
-      &mut self,
-      defaults: &Self::Defaults,
-      layout: Layout<'_>,
-      cursor_position: Point,
-      viewport: &Rectangle,
-      columns: &[TableColumn],
-      data: &[T],
-      is_orderable: bool,
-      text_size: u16,
-      padding: u16,
-      font: Self::Font,
-      style: &Self::Style,
-   ) -> Self::Output;
+   fn overlay(&mut self, layout: Layout<'_>) -> Option<overlay::Element<'_, Message, Renderer>> {
+      let scroll = self.scroll(layout);
+      let editing = match self.state.editing.as_ref().filter(|editing| !editing.is_closed) {
+         Some(editing) => editing,
+         None if self.state.context_menu.is_some() => {
+            let items = self.state.context_menu.and_then(|menu| self.menu_items(menu.target))?;
+            let menu = self.state.context_menu.as_mut()?;
+            let position = menu.position;
+            let context_menu = ContextMenu::new(menu, items, self.text_size, self.padding, self.font, &self.style);
+            return Some(overlay::Element::new(position, Box::new(context_menu)));
+         }
+         None => {
+            let (text, anchor) = self.state.tooltip.as_ref()?;
+            let tooltip = Tooltip::new(text, *anchor, self.text_size, self.padding, self.font, &self.style);
+            return Some(overlay::Element::new(Point::new(anchor.x, anchor.y + anchor.height), Box::new(tooltip)));
+         }
+      };
+      let column = self.columns.iter().position(|column| column.name == editing.column)?;
+      let position = self.state.page_lines().iter().position(|&line| line == Line::Record(editing.row))?;
+      let is_pinned = column < self.pinned_columns;
+      let cell = layout.children().nth(2)?.children().next()?.children().nth(column)?;
+      let bounds = scroll.cell(cell.bounds(), is_pinned, position);
+      let region = if is_pinned { scroll.pinned_bounds(scroll.bounds) } else { scroll.scrolled_bounds(scroll.bounds) };
+
+      // the editor is hidden while its cell is scrolled out of the body
+      if bounds.y < scroll.bounds.y || bounds.y + bounds.height > scroll.bounds.y + scroll.bounds.height || bounds.intersection(&region).is_none() {
+         return None;
+      }
+
+      let editing = self.state.editing.as_mut()?;
+      let record = self.data.get_mut(editing.row)?;
+      let editor = Editor::new(editing, record, bounds.size(), self.on_edit.as_deref(), self.text_size, self.padding, self.font, &self.style);
+      Some(overlay::Element::new(bounds.position(), Box::new(editor)))
+   }
 }
 
-impl Renderer for iced_wgpu::Renderer
+impl<'a, T, Message, Renderer> From<Table<'a, T, Message, Renderer>> for Element<'a, Message, Renderer>
 where
+   T: TableData,
+   Renderer: 'a + self::Renderer + number_input::Renderer,
+   Message: 'a,
 {
-   type Style = Box<dyn StyleSheet>;
-   const DEFAULT_PADDING: u16 = 6;
-   const DEFAULT_HEADER_SPACING: u16 = 1;
-
-   fn draw<T: TableData>(
-      &mut self,
-      defaults: &Self::Defaults,
-      layout: Layout<'_>,
-      cursor_position: Point,
-      viewport: &Rectangle,
-      columns: &[TableColumn],
-      data: &[T],
-      is_orderable: bool,
-      text_size: u16,
-      padding: u16,
-      font: Self::Font,
-      style: &Self::Style,
-   ) -> Self::Output {
-      let mut children = layout.children();
-      let header_layout = children.next().unwrap();
-      let divider_bounds = children.next().unwrap().bounds();
-      let body_layout = children.next().unwrap();
-      let header_bounds = header_layout.bounds();
-      let header_mouse_over = header_bounds.contains(cursor_position);
-      let styling = if header_mouse_over {
-         style.header_hoverd()
-      } else {
-         style.active()
-      };
+   fn from(table: Table<'a, T, Message, Renderer>) -> Element<'a, Message, Renderer> {
+      Element::new(table)
+   }
+}
 
-      let background = Primitive::Quad {
-         bounds: layout.bounds(),
-         background: styling.background,
-         border_color: styling.border_color,
-         border_width: styling.border_width,
-         border_radius: styling.border_radius,
-      };
-      let divider = Primitive::Quad {
-         bounds: divider_bounds,
-         background: Background::Color(Color::TRANSPARENT),
-         border_color: styling.border_color,
-         border_width: styling.border_width,
-         border_radius: 0.0,
-      };
-      let header_background = Primitive::Quad {
-         bounds: header_bounds,
-         background: styling.header_background,
-         border_color: Color::TRANSPARENT,
-         border_width: 0.0,
-         border_radius: styling.border_radius,
-      };
+/// The space taken in the first cell of a tree row by its indentation and chevron.
+fn tree_indent(depth: usize, text_size: u16) -> f32 {
+   (depth + 1) as f32 * f32::from(text_size)
+}
 
-      let header_section = Primitive::Group {
-         primitives: columns
-            .iter()
-            .zip(header_layout.children())
-            .map(|(column, layout)| {
-               let bounds = layout.bounds();
-               Primitive::Text {
-                  content: formatted_sortable_column(column.to_string(), column.order),
-                  size: f32::from(text_size),
-                  font,
-                  color: styling.text_color,
-                  bounds: Rectangle {
-                     x: bounds.x + f32::from(padding),
-                     y: bounds.center_y(),
-                     ..bounds
-                  },
-                  horizontal_alignment: HorizontalAlignment::Left,
-                  vertical_alignment: VerticalAlignment::Center,
-               }
-            })
-            .collect(),
-      };
+/// Where the chevron of a tree node is drawn in its first cell.
+fn chevron_bounds(cell: Rectangle, depth: usize, text_size: u16, padding: u16) -> Rectangle {
+   Rectangle {
+      x: cell.x + f32::from(padding) + tree_indent(depth, text_size) - f32::from(text_size),
+      width: f32::from(text_size),
+      ..cell
+   }
+}
 
-      let mut body_records = Vec::with_capacity(data.len());
-      for (record, record_layout) in data.iter().zip(body_layout.children()) {
-         // let record_bg = if idx%2==0 {
-         //    Primitive::Quad {
-         //       bounds: record_layout.bounds(),
-         //       background: styling.header_background,
-         //       border_color: Color::TRANSPARENT,
-         //       border_radius: styling.border_radius,
-         //       border_width: 0.0
-         //    } 
-         // } else {
-         //    Primitive::None
-         // };
-         let record_cells = columns
-            .iter()
-            .map(|c| c.name.as_str())
-            .map(|name| record.get_field_value(name))
-            .filter_map(|h| h.ok())
-            .zip(record_layout.children())
-            .fold(Vec::with_capacity(columns.len()), |mut record_cells, (value, cell_layout)| {
-               let bounds = cell_layout.bounds();
-               let text = Primitive::Text {
-                  content: serde_json::to_string(&value).unwrap(),
-                  // content: serde_json::from_value(value).unwrap(),
-                  size: f32::from(text_size),
-                  font,
-                  color: styling.text_color,
-                  bounds: Rectangle {
-                     x: bounds.x + f32::from(padding),
-                     y: bounds.center_y(),
-                     ..bounds
-                  },
-                  horizontal_alignment: HorizontalAlignment::Left,
-                  vertical_alignment: VerticalAlignment::Center,
-               };
-               record_cells.push(
-                  Primitive::Clip {
-                     bounds,
-                     content: Box::new(text),
-                     offset: Vector::new(0, 0)
-                  }
-               );
-               record_cells
-            });
-         let record = Primitive::Group{ primitives: record_cells };
-         body_records.push(record);
+/// Returns the text of a cell as drawn in `width`, ending with an ellipsis when it is
+/// too wide for an `Ellipsis` column, and whether part of it is not shown.
+fn fit_text<R: text::Renderer>(renderer: &R, overflow: TextOverflow, content: String, width: f32, text_size: u16, font: R::Font) -> (String, bool) {
+   let measure = |text: &str| renderer.measure(text, text_size, font, Size::new(f32::INFINITY, f32::INFINITY)).0;
+   if overflow == TextOverflow::Wrap || measure(&content) <= width {
+      return (content, false);
+   }
+   if overflow == TextOverflow::Clip {
+      return (content, true);
+   }
+   // the longest run of whole characters that fits followed by the ellipsis
+   let chars: Vec<char> = content.chars().collect();
+   let shortened = |count: usize| format!("{}…", chars[..count].iter().collect::<String>().trim_end());
+   let (mut low, mut high) = (0, chars.len());
+   while low < high {
+      let mid = (low + high + 1) / 2;
+      if measure(&shortened(mid)) <= width {
+         low = mid;
+      } else {
+         high = mid - 1;
       }
-      let body_section = Primitive::Group{ primitives: body_records };
-      (
-         Primitive::Group{ primitives: vec![background, header_background, body_section, divider, header_section] },
-         if header_mouse_over && is_orderable {
-            mouse::Interaction::Pointer
-         } else {
-            mouse::Interaction::default()
-         },
-      )
    }
+   (shortened(low), true)
 }
 
-#[derive(Debug)]
-enum TableEvent {
-   SortColumn(usize),
+fn sort_priority(sort_keys: &[SortKey], column: &str) -> Option<usize> {
+   if sort_keys.len() > 1 {
+      sort_keys.iter().position(|key| key.column == column).map(|idx| idx + 1)
+   } else {
+      None
+   }
 }
 
-impl<'a, T, Message, Renderer> From<Table<'a, T, Renderer>> for Element<'a, Message, Renderer>
-where
-   T: TableData,
-   Renderer: 'a + self::Renderer,
-   Message: Clone + 'static,
-{
-   fn from(table: Table<'a, T, Renderer>) -> Element<'a, Message, Renderer> {
-      Element::new(table)
+/// Returns the label of a header in `width`, the short name of the column when the label
+/// does not fit and the short name is narrower, and whether the full label is not shown.
+fn header_label<R: text::Renderer>(renderer: &R, column: &TableColumn, sort_keys: &[SortKey], width: f32, text_size: u16, padding: u16, font: R::Font) -> (String, bool) {
+   let priority = sort_priority(sort_keys, &column.name);
+   let label = formatted_sortable_column(column.to_string(), column.order, priority);
+   let measure = |text: &str| renderer.measure(text, text_size, font, Size::new(f32::INFINITY, f32::INFINITY)).0;
+   let label_width = measure(&label);
+   if label_width + f32::from(padding) * 2.0 <= width {
+      return (label, false);
+   }
+   match column.short_name.as_ref().map(|short_name| formatted_sortable_column(short_name.clone(), column.order, priority)) {
+      Some(short_label) if measure(&short_label) < label_width => (short_label, true),
+      _ => (label, true),
    }
 }
 
-fn formatted_sortable_column(label: String, order: TableOrder) -> String {
-   match order {
+fn formatted_sortable_column(label: String, order: TableOrder, priority: Option<usize>) -> String {
+   let label = match order {
       TableOrder::Unordered => label,
       TableOrder::Ascending => format!("{} ▲", label),
       TableOrder::Descending => format!("{} ▼", label),
+   };
+   match priority {
+      Some(priority) if order != TableOrder::Unordered => format!("{}{}", label, priority),
+      _ => label,
    }
-}
\ No newline at end of file
+}
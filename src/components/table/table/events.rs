@@ -0,0 +1,792 @@
+use super::{
+   chevron_bounds, export, fit_text, header_label, tree_indent, ColumnDrag, ColumnInteraction, ColumnLayout, ColumnResize, Editing, ExportFormat, Filter,
+   HeaderAction, Line, MenuTarget, OpenMenu, PageButton, Renderer, Scroll, ScrollAxis, SelectionMode, SortKey, Table, TableData, TableOrder,
+   DOUBLE_CLICK_INTERVAL, DRAG_THRESHOLD, MIN_COLUMN_WIDTH, RESIZE_HANDLE_WIDTH, SCROLL_LINE_HEIGHT,
+};
+use iced_native::{event::{self, Event}, keyboard, mouse, Clipboard, Layout, Point, Rectangle, Widget};
+use std::time::Instant;
+
+impl<'a, T, Message, Renderer> Table<'a, T, Message, Renderer>
+where
+   T: TableData,
+   Renderer: self::Renderer,
+{
+   pub(super) fn scroll_to(&mut self, scroll: &Scroll, axis: ScrollAxis, percentage: f32) {
+      match axis {
+         ScrollAxis::Vertical => self.state.scrollable.scroll_to(percentage, scroll.bounds, scroll.content_bounds()),
+         ScrollAxis::Horizontal => self.state.scroll_x = scroll.max_offset_x() * percentage,
+      }
+   }
+
+   /// Returns whether the column can be read from the records, which it is sorted by.
+   pub(super) fn is_sortable(&self, idx: usize) -> bool {
+      let name = &self.columns[idx].name;
+      self.data.get(0).map_or(true, |record| record.get_field_value(name).is_ok())
+   }
+
+   pub(super) fn trigger_sort_column(&mut self, idx: usize, messages: &mut Vec<Message>) {
+      use TableOrder::*;
+
+      if !self.is_sortable(idx) {
+         return;
+      }
+
+      let name = self.columns[idx].name.clone();
+      let order = self.state.sort_order(&name).toggle();
+      let sort_keys = &mut self.state.sort_keys;
+      if self.state.modifiers.shift {
+         match sort_keys.iter().position(|key| key.column == name) {
+            Some(pos) if order == Unordered => { sort_keys.remove(pos); }
+            Some(pos) => sort_keys[pos].order = order,
+            None => sort_keys.push(SortKey::new(name.clone(), Ascending)),
+         }
+      } else if sort_keys.len() == 1 && sort_keys[0].column == name {
+         if order == Unordered {
+            sort_keys.clear();
+         } else {
+            sort_keys[0].order = order;
+         }
+      } else {
+         *sort_keys = vec![SortKey::new(name.clone(), Ascending)];
+      }
+      self.sort_changed(&name, messages);
+   }
+
+   pub(super) fn sort_changed(&mut self, name: &str, messages: &mut Vec<Message>) {
+      self.refresh();
+      for column in self.columns.iter_mut() {
+         column.order = self.state.sort_order(&column.name);
+      }
+      if let Some(on_sort) = &self.on_sort {
+         messages.push(on_sort(name, self.state.sort_order(name)));
+      }
+   }
+
+   pub(super) fn select_row(&mut self, row: usize, column: Option<usize>, messages: &mut Vec<Message>) {
+      let now = Instant::now();
+      let is_double_click = match self.state.last_click {
+         Some((last_row, at)) => last_row == row && now.duration_since(at) <= DOUBLE_CLICK_INTERVAL,
+         None => false,
+      };
+
+      let modifiers = self.state.modifiers;
+      if self.selection_mode == SelectionMode::Multiple && modifiers.is_command_pressed() {
+         if !self.state.selection.remove(&row) {
+            self.state.selection.insert(row);
+         }
+         self.state.selection_anchor = Some(row);
+         self.state.selected_row = Some(row);
+      } else if self.selection_mode == SelectionMode::Multiple && modifiers.shift {
+         self.select_range(row);
+      } else {
+         self.state.select_row(Some(row));
+      }
+      self.selection_changed(messages);
+
+      if is_double_click && !modifiers.shift && !modifiers.is_command_pressed() {
+         self.state.last_click = None;
+         if !column.map_or(false, |column| self.edit_cell(row, column)) {
+            self.activate_row(row, messages);
+         }
+      } else {
+         self.state.last_click = Some((row, now));
+      }
+   }
+
+   /// Selects the rows shown between the anchor and `row`, both included.
+   pub(super) fn select_range(&mut self, row: usize) {
+      let anchor = self.state.selection_anchor.unwrap_or(row);
+      let rows = self.state.shown_records();
+      let (start, end) = match (rows.iter().position(|&idx| idx == anchor), rows.iter().position(|&idx| idx == row)) {
+         (Some(start), Some(end)) => (start.min(end), start.max(end)),
+         _ => return self.state.select_row(Some(row)),
+      };
+      self.state.selection = rows[start..=end].iter().copied().collect();
+      self.state.selection_anchor = Some(anchor);
+      self.state.selected_row = Some(row);
+   }
+
+   pub(super) fn selection_changed(&self, messages: &mut Vec<Message>) {
+      if let Some((on_select, row)) = self.on_select.as_ref().zip(self.state.selected_row) {
+         if let Some(record) = self.data.get(row) {
+            messages.push(on_select(row, record));
+         }
+      }
+      if let Some(on_selection_change) = &self.on_selection_change {
+         messages.push(on_selection_change(self.state.selection.iter().copied().collect()));
+      }
+   }
+
+   /// Moves the current row with the navigation keys, returns whether the key is one of them.
+   pub(super) fn navigate(&mut self, key_code: keyboard::KeyCode, modifiers: keyboard::Modifiers, scroll: &Scroll, messages: &mut Vec<Message>) -> bool {
+      use keyboard::KeyCode;
+
+      let rows = self.state.shown_records();
+      let count = rows.len();
+      if self.selection_mode == SelectionMode::Multiple && key_code == KeyCode::A && modifiers.is_command_pressed() {
+         self.state.select_all();
+         self.selection_changed(messages);
+         return true;
+      }
+      if count == 0 {
+         return false;
+      }
+
+      let current = self.state.selected_row.and_then(|row| rows.iter().position(|&idx| idx == row));
+      let page = if scroll.row_height > 0.0 { ((scroll.bounds.height / scroll.row_height) as usize).max(1) } else { 1 };
+      let position = match (key_code, current) {
+         (KeyCode::Up, Some(position)) => position.saturating_sub(1),
+         (KeyCode::Down, Some(position)) => (position + 1).min(count - 1),
+         (KeyCode::PageUp, Some(position)) => position.saturating_sub(page),
+         (KeyCode::PageDown, Some(position)) => (position + page).min(count - 1),
+         (KeyCode::Up, None) | (KeyCode::Down, None) | (KeyCode::PageUp, None) | (KeyCode::PageDown, None) | (KeyCode::Home, _) => 0,
+         (KeyCode::End, _) => count - 1,
+         _ => return false,
+      };
+
+      let row = rows[position];
+      if self.selection_mode == SelectionMode::Multiple && modifiers.shift {
+         self.select_range(row);
+      } else {
+         self.state.select_row(Some(row));
+      }
+      self.state.last_click = None;
+      // on a paginated table the keys move across pages
+      let position = self.state.lines.iter().position(|&line| line == Line::Record(row)).unwrap_or(position);
+      let mut page_start = 0;
+      if self.state.is_paginated && self.state.page_size > 0 {
+         let page = position / self.state.page_size;
+         if page != self.state.page() {
+            self.state.page = page;
+            if let Some(on_page_change) = &self.on_page_change {
+               messages.push(on_page_change(page));
+            }
+         }
+         page_start = page * self.state.page_size;
+      }
+      self.scroll_to_position(scroll, position - page_start);
+      self.selection_changed(messages);
+      true
+   }
+
+   /// Scrolls the body the least needed to show the row at the given position.
+   pub(super) fn scroll_to_position(&mut self, scroll: &Scroll, position: usize) {
+      let top = scroll.row_height * position as f32;
+      let bottom = top + scroll.row_height;
+      let delta_y = if top < scroll.offset.y {
+         scroll.offset.y - top
+      } else if bottom > scroll.offset.y + scroll.bounds.height {
+         scroll.offset.y + scroll.bounds.height - bottom
+      } else {
+         return;
+      };
+      self.state.scrollable.scroll(delta_y, scroll.bounds, scroll.content_bounds());
+   }
+
+   /// Opens the editor of a cell, returns whether the cell can be edited.
+   pub(super) fn edit_cell(&mut self, row: usize, column: usize) -> bool {
+      let column = &self.columns[column];
+      if !column.editable {
+         return false;
+      }
+      self.state.editing = self.data.get(row).and_then(|record| record.get_field_value(&column.name).ok()).and_then(|value| Editing::new(row, &column.name, &value));
+      self.state.editing.is_some()
+   }
+
+   /// Returns the column of the cell under the cursor, pinned columns first.
+   pub(super) fn column_at(&self, layout: Layout<'_>, scroll: &Scroll, cursor_position: Point) -> Option<usize> {
+      let row_layout = layout.children().nth(2)?.children().next()?;
+      row_layout.children().enumerate().position(|(idx, cell)| {
+         let is_pinned = idx < self.pinned_columns;
+         let region = if is_pinned { scroll.pinned_bounds(scroll.bounds) } else { scroll.scrolled_bounds(scroll.bounds) };
+         let bounds = scroll.cell(cell.bounds(), is_pinned, 0);
+         region.contains(cursor_position) && cursor_position.x >= bounds.x && cursor_position.x < bounds.x + bounds.width
+      })
+   }
+
+   pub(super) fn footer_button_at(&self, layout: Layout<'_>, cursor_position: Point) -> Option<PageButton> {
+      let footer_layout = layout.children().nth(4)?;
+      let page_count = self.state.page_count();
+      PageButton::all(&self.page_sizes, page_count)
+         .into_iter()
+         .zip(footer_layout.children())
+         .find(|(_, layout)| layout.bounds().contains(cursor_position))
+         .map(|(button, _)| button)
+   }
+
+   pub(super) fn press_footer_button(&mut self, button: PageButton, scroll: &Scroll, messages: &mut Vec<Message>) {
+      let page = self.state.page();
+      match button {
+         PageButton::Size(size) => self.state.set_page_size(size),
+         button => self.state.page = button.page(page, self.state.page_count()),
+      }
+      if self.state.page() != page {
+         self.state.scrollable.scroll_to(0.0, scroll.bounds, scroll.content_bounds());
+         if let Some(on_page_change) = &self.on_page_change {
+            messages.push(on_page_change(self.state.page()));
+         }
+      }
+   }
+
+   /// Returns the column whose header is under the cursor.
+   pub(super) fn header_column_at(&self, header_layout: Layout<'_>, scroll: &Scroll, cursor_position: Point) -> Option<usize> {
+      header_layout.children().enumerate().position(|(idx, layout)| {
+         let is_pinned = idx < self.pinned_columns;
+         let region = if is_pinned { scroll.pinned_bounds(header_layout.bounds()) } else { scroll.scrolled_bounds(header_layout.bounds()) };
+         region.contains(cursor_position) && scroll.header_cell(layout.bounds(), is_pinned).contains(cursor_position)
+      })
+   }
+
+   /// Returns the tooltip of the header under the cursor, its own or the full label
+   /// when the header is too narrow for it, or the full text of a cut cell.
+   pub(super) fn tooltip_at(&self, renderer: &Renderer, layout: Layout<'_>, scroll: &Scroll, cursor_position: Point) -> Option<(String, Rectangle)> {
+      if self.state.editing.as_ref().map_or(false, |editing| !editing.is_closed) {
+         return None;
+      }
+      let header_layout = layout.children().next()?;
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      if let Some(idx) = self.header_column_at(header_layout, scroll, cursor_position) {
+         let column = self.columns.get(idx)?;
+         let bounds = scroll.header_cell(header_layout.children().nth(idx)?.bounds(), idx < self.pinned_columns);
+         let (_, is_cut) = header_label(renderer, column, &self.state.sort_keys, bounds.width, text_size, self.padding, self.font);
+         return match &column.tooltip {
+            Some(tooltip) => Some((tooltip.clone(), bounds)),
+            None if is_cut => Some((column.to_string(), bounds)),
+            None => None,
+         };
+      }
+
+      let position = scroll.row_at(cursor_position, self.state.page_lines().len())?;
+      let row = match self.state.page_lines()[position] {
+         Line::Record(row) => row,
+         Line::Group(_) => return None,
+      };
+      let idx = self.column_at(layout, scroll, cursor_position)?;
+      let column = self.columns.get(idx).filter(|column| !self.cell_views.contains_key(&column.name))?;
+      let content = self.data.get(row)?.get_field_value(&column.name).ok().and_then(|value| column.kind.format(&value))?;
+      let cell = layout.children().nth(2)?.children().next()?.children().nth(idx)?;
+      let bounds = scroll.cell(cell.bounds(), idx < self.pinned_columns, position);
+      let indent = self.state.tree.get(&row).filter(|_| idx == 0).map_or(0.0, |node| tree_indent(node.depth, text_size));
+      let (_, is_cut) = fit_text(renderer, column.overflow, content.clone(), bounds.width - indent - f32::from(self.padding) * 2.0, text_size, self.font);
+      if is_cut {
+         Some((content, bounds))
+      } else {
+         None
+      }
+   }
+
+   /// Returns the column whose right border in the header is under the cursor.
+   pub(super) fn resize_handle_at(&self, header_layout: Layout<'_>, scroll: &Scroll, cursor_position: Point) -> Option<usize> {
+      if !self.is_resizable || !header_layout.bounds().contains(cursor_position) {
+         return None;
+      }
+      header_layout.children().enumerate().position(|(idx, layout)| {
+         let is_pinned = idx < self.pinned_columns;
+         let region = if is_pinned { scroll.pinned_bounds(header_layout.bounds()) } else { scroll.scrolled_bounds(header_layout.bounds()) };
+         let bounds = scroll.header_cell(layout.bounds(), is_pinned);
+         let border = bounds.x + bounds.width;
+         (cursor_position.x - border).abs() <= RESIZE_HANDLE_WIDTH && region.x <= border && border <= region.x + region.width
+      })
+   }
+
+   /// Returns where a dragged column would be dropped, as the position it takes
+   /// among the shown columns and the x coordinate of the border it lands on.
+   pub(super) fn drop_target(&self, header_layout: Layout<'_>, scroll: &Scroll, cursor_position: Point) -> Option<(usize, f32)> {
+      let cells = header_layout.children().enumerate().map(|(idx, layout)| scroll.header_cell(layout.bounds(), idx < self.pinned_columns));
+      let mut last_border = None;
+      for (idx, bounds) in cells.enumerate() {
+         if cursor_position.x < bounds.center_x() {
+            return Some((idx, bounds.x));
+         }
+         last_border = Some(bounds.x + bounds.width);
+      }
+      last_border.map(|border| (self.columns.len(), border))
+   }
+
+   /// Moves a shown column before the one at `target`, or after the last one,
+   /// keeping hidden columns in place. Returns whether the order changed.
+   pub(super) fn move_column(&mut self, column: usize, target: usize) -> bool {
+      if target == column || target == column + 1 || column >= self.columns.len() {
+         return false;
+      }
+      let name = self.columns[column].name.clone();
+      let mut order = self.column_order.clone();
+      order.retain(|other| *other != name);
+      let position = match self.columns.get(target) {
+         Some(before) => order.iter().position(|other| *other == before.name),
+         None => self.columns.last().and_then(|last| order.iter().position(|other| *other == last.name)).map(|position| position + 1),
+      };
+      order.insert(position.unwrap_or(order.len()), name);
+      self.state.column_layout.order = order.clone();
+      self.column_order = order;
+      true
+   }
+
+   pub(super) fn column_layout_changed(&self, messages: &mut Vec<Message>) {
+      if let Some(on_column_layout_change) = &self.on_column_layout_change {
+         messages.push(on_column_layout_change(self.state.column_layout.clone()));
+      }
+   }
+
+   /// Returns what the header shows while columns are resized or moved.
+   pub(super) fn column_interaction(&self, layout: Layout<'_>, scroll: &Scroll, cursor_position: Point) -> ColumnInteraction {
+      let header_layout = layout.children().next().unwrap();
+      if let Some(resizing) = self.state.resizing {
+         let border = header_layout.children().nth(resizing.column).zip(self.columns.get(resizing.column)).map(|(cell, column)| {
+            let bounds = scroll.header_cell(cell.bounds(), resizing.column < self.pinned_columns);
+            bounds.x + self.state.column_layout.width(&column.name).unwrap_or(bounds.width)
+         });
+         if let Some(border) = border {
+            return ColumnInteraction::Resizing(border);
+         }
+      }
+      match self.state.dragged_column {
+         Some(drag) if drag.is_moved => match self.drop_target(header_layout, scroll, cursor_position) {
+            Some((_, border)) => ColumnInteraction::Moving(border),
+            None => ColumnInteraction::Idle,
+         },
+         _ if self.resize_handle_at(header_layout, scroll, cursor_position).is_some() => ColumnInteraction::ResizeHandle,
+         _ => ColumnInteraction::Idle,
+      }
+   }
+
+   /// Passes an event to the filter inputs of the header, and filters the rows again once one changed.
+   pub(super) fn update_filter_inputs(&mut self, event: Event, header_layout: Layout<'_>, scroll: &Scroll, cursor_position: Point, renderer: &Renderer, clipboard: Option<&dyn Clipboard>) -> event::Status {
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let mut event_status = event::Status::Ignored;
+      let mut changes = Vec::new();
+
+      for (idx, (column, cell)) in self.columns.iter().zip(header_layout.children()).enumerate() {
+         let input_layout = match cell.children().next() {
+            Some(input_layout) => input_layout,
+            None => continue,
+         };
+         let is_pinned = idx < self.pinned_columns;
+         let region = if is_pinned { scroll.pinned_bounds(header_layout.bounds()) } else { scroll.scrolled_bounds(header_layout.bounds()) };
+         // an input scrolled out of its region cannot be clicked
+         let cursor_position = if region.contains(cursor_position) {
+            cursor_position - (scroll.header_cell(cell.bounds(), is_pinned).position() - cell.bounds().position())
+         } else {
+            Point::new(-1.0, -1.0)
+         };
+         let value = self.filter_text(&column.name);
+         let state = self.state.filter_inputs.entry(column.name.clone()).or_default();
+         let mut texts = Vec::new();
+         let status = Self::filter_input(state, &value, text_size, self.padding, self.font).on_event(event.clone(), input_layout, cursor_position, &mut texts, renderer, clipboard);
+         event_status = event_status.merge(status);
+         if let Some(text) = texts.pop() {
+            changes.push((column.name.clone(), text));
+         }
+      }
+
+      if !changes.is_empty() {
+         for (column, text) in changes {
+            if text.is_empty() {
+               self.state.remove_filter(&column);
+            } else {
+               self.state.set_filter(column, Filter::Contains(text));
+            }
+         }
+         self.refresh();
+      }
+      event_status
+   }
+
+   /// Asks the source to load the records shown, with `on_fetch` for those it cannot provide yet.
+   pub(super) fn fetch_shown(&mut self, scroll: &Scroll, messages: &mut Vec<Message>) {
+      let on_fetch = match &self.on_fetch {
+         Some(on_fetch) => on_fetch,
+         None => return,
+      };
+      let lines = self.state.page_lines();
+      let shown = scroll.visible_rows(&scroll.bounds, lines.len());
+      let (start, end) = lines[shown].iter().fold((usize::MAX, 0), |(start, end), line| match line {
+         Line::Record(row) => (start.min(*row), end.max(row + 1)),
+         Line::Group(_) => (start, end),
+      });
+      // the records are fetched once scrolled into view or shown for a new query
+      let fetched = Some((start..end, self.state.revision));
+      if start < end && self.state.fetched != fetched {
+         self.state.fetched = fetched;
+         messages.extend(self.data.fetch(start..end).into_iter().map(on_fetch));
+      }
+   }
+
+   pub(super) fn activate_row(&self, row: usize, messages: &mut Vec<Message>) {
+      if let (Some(on_activate), Some(record)) = (&self.on_activate, self.data.get(row)) {
+         messages.push(on_activate(row, record));
+      }
+   }
+
+   /// Expands or collapses a node of a tree table.
+   pub(super) fn toggle_node(&mut self, row: usize, messages: &mut Vec<Message>) {
+      let (tree, record) = match (&self.tree, self.data.get(row)) {
+         (Some(tree), Some(record)) => (tree, record),
+         _ => return,
+      };
+      let key = (tree.key)(record);
+      let is_expanded = !self.state.is_expanded(&key);
+      if let (true, Some(on_expand)) = (is_expanded, &self.on_expand) {
+         messages.push(on_expand(row, record));
+      }
+      self.state.set_expanded(key, is_expanded);
+   }
+
+   /// Returns whether the cursor is on the chevron of a row of a tree table.
+   pub(super) fn is_on_chevron(&self, layout: Layout<'_>, scroll: &Scroll, position: usize, row: usize, text_size: u16, cursor_position: Point) -> bool {
+      let node = match self.state.tree.get(&row) {
+         Some(node) if node.is_expandable => node,
+         _ => return false,
+      };
+      let first_cell = layout.children().nth(2).and_then(|body| body.children().next()).and_then(|row| row.children().next());
+      first_cell.map_or(false, |cell| {
+         let bounds = scroll.cell(cell.bounds(), self.pinned_columns > 0, position);
+         chevron_bounds(bounds, node.depth, text_size, self.padding).contains(cursor_position)
+      })
+   }
+
+   /// Expands the current node with Right and collapses it with Left, or moves to its parent
+   /// when collapsed. Returns whether the key was handled.
+   pub(super) fn navigate_tree(&mut self, key_code: keyboard::KeyCode, messages: &mut Vec<Message>) -> bool {
+      let (tree, row) = match (&self.tree, self.state.selected_row) {
+         (Some(tree), Some(row)) => (tree, row),
+         _ => return false,
+      };
+      let node = match self.state.tree.get(&row) {
+         Some(node) => *node,
+         None => return false,
+      };
+      match key_code {
+         keyboard::KeyCode::Right if node.is_expandable && !node.is_expanded => self.toggle_node(row, messages),
+         keyboard::KeyCode::Left if node.is_expanded => self.toggle_node(row, messages),
+         keyboard::KeyCode::Left => {
+            let parent_key = match self.data.get(row).and_then(|record| (tree.parent_key)(record)) {
+               Some(parent_key) => parent_key,
+               None => return false,
+            };
+            let parent = self.state.rows.iter().copied().find(|&other| self.data.get(other).map_or(false, |record| (tree.key)(record) == parent_key));
+            match parent {
+               Some(parent) => {
+                  self.state.select_row(Some(parent));
+                  self.selection_changed(messages);
+               }
+               None => return false,
+            }
+         }
+         _ => return false,
+      }
+      true
+   }
+
+   pub(super) fn header_menu_items(&self, column: usize) -> Vec<(HeaderAction, &'static str, bool)> {
+      let is_sortable = self.is_orderable() && self.is_sortable(column);
+      vec![
+         (HeaderAction::Sort(TableOrder::Ascending), "Sort ascending", is_sortable),
+         (HeaderAction::Sort(TableOrder::Descending), "Sort descending", is_sortable),
+         // the last shown column stays
+         (HeaderAction::Hide, "Hide column", self.columns.len() > 1),
+         (HeaderAction::ResetColumns, "Reset columns", self.state.column_layout != ColumnLayout::default()),
+      ]
+   }
+
+   /// Returns the labels of the items of an open menu and whether they can be chosen.
+   pub(super) fn menu_items(&self, target: MenuTarget) -> Option<Vec<(String, bool)>> {
+      match target {
+         MenuTarget::Row(row) => {
+            let items = (self.row_menu.as_ref()?)(self.data.get(row)?);
+            Some(items.into_iter().map(|item| (item.label, item.message.is_some())).collect())
+         }
+         MenuTarget::Header(column) if column < self.columns.len() => {
+            Some(self.header_menu_items(column).into_iter().map(|(_, label, is_enabled)| (label.to_string(), is_enabled)).collect())
+         }
+         MenuTarget::Header(_) => None,
+      }
+   }
+
+   /// Opens the menu of the record or header under the cursor, if it has one.
+   pub(super) fn open_menu(&mut self, layout: Layout<'_>, scroll: &Scroll, cursor_position: Point, messages: &mut Vec<Message>) -> bool {
+      let header_layout = layout.children().next().unwrap();
+      let target = if header_layout.bounds().contains(cursor_position) {
+         self.header_column_at(header_layout, scroll, cursor_position).filter(|_| self.header_menu).map(MenuTarget::Header)
+      } else {
+         match scroll.row_at(cursor_position, self.state.page_lines().len()).map(|position| self.state.page_lines()[position]) {
+            Some(Line::Record(row)) if self.row_menu.is_some() => {
+               if !self.state.is_selected(row) {
+                  self.state.select_row(Some(row));
+                  self.selection_changed(messages);
+               }
+               Some(MenuTarget::Row(row))
+            }
+            _ => None,
+         }
+      };
+      self.state.context_menu = target.map(|target| OpenMenu::new(target, cursor_position));
+      self.state.context_menu.is_some()
+   }
+
+   /// Runs the chosen item of a menu.
+   pub(super) fn choose_menu_item(&mut self, target: MenuTarget, item: usize, messages: &mut Vec<Message>) {
+      match target {
+         MenuTarget::Row(row) => {
+            let message = self.row_menu.as_ref().zip(self.data.get(row)).and_then(|(row_menu, record)| row_menu(record).into_iter().nth(item)).and_then(|item| item.message);
+            messages.extend(message);
+         }
+         MenuTarget::Header(column) if column < self.columns.len() => {
+            let name = self.columns[column].name.clone();
+            match self.header_menu_items(column).get(item) {
+               Some(&(HeaderAction::Sort(order), _, true)) => {
+                  self.state.sort_keys = vec![SortKey::new(name.clone(), order)];
+                  self.sort_changed(&name, messages);
+               }
+               Some((HeaderAction::Hide, _, true)) => {
+                  self.state.set_column_hidden(&name, true);
+                  self.column_layout_changed(messages);
+               }
+               Some((HeaderAction::ResetColumns, _, true)) => {
+                  self.state.set_column_layout(ColumnLayout::default());
+                  self.column_layout_changed(messages);
+               }
+               _ => {}
+            }
+         }
+         MenuTarget::Header(_) => {}
+      }
+   }
+
+   /// Hands an event to the content of the cells drawn with a view, in the rows laid out with them.
+   #[allow(clippy::too_many_arguments)]
+   pub(super) fn cell_views_event(&self, event: Event, layout: Layout<'_>, scroll: &Scroll, cursor_position: Point, messages: &mut Vec<Message>, renderer: &Renderer, clipboard: Option<&dyn Clipboard>) -> event::Status {
+      let body_layout = match layout.children().nth(2) {
+         Some(body_layout) => body_layout,
+         None => return event::Status::Ignored,
+      };
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let (first_shown, _) = self.state.shown.get();
+      let editing_cell = self.state.editing_cell();
+      let mut event_status = event::Status::Ignored;
+      for (position, row_layout) in (first_shown..).zip(body_layout.children().skip(1)) {
+         let (row, record) = match self.state.page_lines().get(position) {
+            Some(&Line::Record(row)) => match self.data.get(row) {
+               Some(record) => (row, record),
+               None => continue,
+            },
+            _ => continue,
+         };
+         for (column_idx, (column, cell_layout)) in self.columns.iter().zip(row_layout.children()).enumerate() {
+            let (view, content_layout) = match (self.cell_views.get(&column.name), cell_layout.children().next()) {
+               (Some(view), Some(content_layout)) if editing_cell != Some((row, column.name.as_str())) => (view, content_layout),
+               _ => continue,
+            };
+            let value = match record.get_field_value(&column.name) {
+               Ok(value) => value,
+               Err(_) => continue,
+            };
+            // the content is moved like when drawn, the cursor only reaches it within the body
+            let mut bounds = scroll.cell(cell_layout.bounds(), column_idx < self.pinned_columns, position);
+            if let Some(node) = self.state.tree.get(&row).filter(|_| column_idx == 0) {
+               bounds.x += tree_indent(node.depth, text_size);
+            }
+            let cursor_position = if scroll.bounds.contains(cursor_position) {
+               cursor_position - (bounds.position() - cell_layout.bounds().position())
+            } else {
+               Point::new(-1.0, -1.0)
+            };
+            if view(&value).on_event(event.clone(), content_layout, cursor_position, messages, renderer, clipboard) == event::Status::Captured {
+               event_status = event::Status::Captured;
+            }
+         }
+      }
+      event_status
+   }
+
+   /// Handles an event on the table, its header, rows, filters and footer.
+   pub(super) fn handle_event(&mut self, event: Event, layout: Layout<'_>, cursor_position: Point, messages: &mut Vec<Message>, renderer: &Renderer, clipboard: Option<&dyn Clipboard>) -> event::Status {
+      let mouse_over = layout.bounds().contains(cursor_position);
+      let header_layout = layout.children().next().unwrap();
+      let scroll = self.scroll(layout);
+      let mut event_status = event::Status::Ignored;
+      self.fetch_shown(&scroll, messages);
+
+      if let Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+         self.state.modifiers = modifiers;
+      }
+
+      // an open menu takes the next press, which chooses one of its items or closes it
+      if let Some(menu) = self.state.context_menu {
+         match event {
+            Event::Mouse(mouse::Event::ButtonPressed(_)) if menu.is_pressed => {
+               self.state.context_menu = Some(OpenMenu { is_pressed: false, ..menu });
+               return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(_)) | Event::Mouse(mouse::Event::WheelScrolled { .. }) | Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::Escape, .. }) => {
+               self.state.context_menu = None;
+               if let Some(item) = menu.chosen {
+                  self.choose_menu_item(menu.target, item, messages);
+               }
+               return event::Status::Captured;
+            }
+            Event::Mouse(_) | Event::Keyboard(keyboard::Event::KeyPressed { .. }) => return event::Status::Ignored,
+            _ => {}
+         }
+      }
+
+      if let Some((axis, grabbed_at)) = self.state.scroller_grabbed_at {
+         match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+               if let Some(scrollbar) = scroll.scrollbar(axis) {
+                  self.scroll_to(&scroll, axis, scrollbar.scroll_percentage(axis, grabbed_at, cursor_position));
+               }
+               return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+               self.state.scroller_grabbed_at = None;
+               return event::Status::Captured;
+            }
+            _ => {}
+         }
+      }
+
+      if let Some(resizing) = self.state.resizing {
+         match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+               if let Some(column) = self.columns.get(resizing.column) {
+                  let width = column.clamp_width(resizing.width + cursor_position.x - resizing.origin_x).max(MIN_COLUMN_WIDTH);
+                  self.state.column_layout.set_width(column.name.clone(), width);
+               }
+               return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+               self.state.resizing = None;
+               self.column_layout_changed(messages);
+               return event::Status::Captured;
+            }
+            _ => {}
+         }
+      }
+
+      if let Some(drag) = self.state.dragged_column {
+         match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+               if self.is_reorderable && (cursor_position.x - drag.origin_x).abs() > DRAG_THRESHOLD {
+                  self.state.dragged_column = Some(ColumnDrag { is_moved: true, ..drag });
+               }
+               return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+               self.state.dragged_column = None;
+               if !drag.is_moved {
+                  if self.is_orderable() && drag.column < self.columns.len() {
+                     self.trigger_sort_column(drag.column, messages);
+                  }
+               } else if let Some((target, _)) = self.drop_target(header_layout, &scroll, cursor_position) {
+                  if self.move_column(drag.column, target) {
+                     self.column_layout_changed(messages);
+                  }
+               }
+               return event::Status::Captured;
+            }
+            _ => {}
+         }
+      }
+
+      if self.header_filters && self.update_filter_inputs(event.clone(), header_layout, &scroll, cursor_position, renderer, clipboard) == event::Status::Captured {
+         return event::Status::Captured;
+      }
+
+      if self.cell_views_event(event.clone(), layout, &scroll, cursor_position, messages, renderer, clipboard) == event::Status::Captured {
+         return event::Status::Captured;
+      }
+
+      match event {
+         Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+            self.state.tooltip = self.tooltip_at(renderer, layout, &scroll, cursor_position);
+         }
+         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
+            self.state.tooltip = None;
+            self.state.is_focused = mouse_over;
+            if mouse_over {
+               event_status = event::Status::Captured;
+               let grabbed = [ScrollAxis::Vertical, ScrollAxis::Horizontal].iter().find_map(|&axis| {
+                  scroll.scrollbar(axis).and_then(|scrollbar| scrollbar.grab(axis, cursor_position).map(|grabbed_at| (axis, grabbed_at, scrollbar)))
+               });
+               if let Some((axis, grabbed_at, scrollbar)) = grabbed {
+                  self.scroll_to(&scroll, axis, scrollbar.scroll_percentage(axis, grabbed_at, cursor_position));
+                  self.state.scroller_grabbed_at = Some((axis, grabbed_at));
+               } else if let Some(column) = self.resize_handle_at(header_layout, &scroll, cursor_position) {
+                  let width = header_layout.children().nth(column).map_or(0.0, |cell| cell.bounds().width);
+                  self.state.resizing = Some(ColumnResize { column, origin_x: cursor_position.x, width });
+               } else if header_layout.bounds().contains(cursor_position) {
+                  // the column is sorted once released without being dragged
+                  if self.is_orderable() || self.is_reorderable {
+                     if let Some(column) = self.header_column_at(header_layout, &scroll, cursor_position) {
+                        self.state.dragged_column = Some(ColumnDrag { column, origin_x: cursor_position.x, is_moved: false });
+                     }
+                  }
+               } else if let Some(position) = scroll.row_at(cursor_position, self.state.page_lines().len()) {
+                  match self.state.page_lines()[position] {
+                     Line::Record(row) if self.is_on_chevron(layout, &scroll, position, row, self.text_size.unwrap_or(renderer.default_size()), cursor_position) => {
+                        self.toggle_node(row, messages);
+                     }
+                     Line::Record(row) => {
+                        let column = self.column_at(layout, &scroll, cursor_position);
+                        self.select_row(row, column, messages);
+                     }
+                     Line::Group(group) => {
+                        let value = self.state.groups[group].value.clone();
+                        let is_collapsed = self.state.is_group_collapsed(&value);
+                        self.state.set_group_collapsed(&value, !is_collapsed);
+                     }
+                  }
+               } else if let Some(button) = self.footer_button_at(layout, cursor_position) {
+                  self.press_footer_button(button, &scroll, messages);
+               }
+            }
+         }
+         Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) if mouse_over => {
+            self.state.tooltip = None;
+            self.state.is_focused = true;
+            if self.open_menu(layout, &scroll, cursor_position, messages) {
+               event_status = event::Status::Captured;
+            }
+         }
+         Event::Mouse(mouse::Event::WheelScrolled { delta }) if mouse_over => {
+            let (delta_x, delta_y) = match delta {
+               mouse::ScrollDelta::Lines { x, y } => (x * SCROLL_LINE_HEIGHT, y * SCROLL_LINE_HEIGHT),
+               mouse::ScrollDelta::Pixels { x, y } => (x, y),
+            };
+            let (delta_x, delta_y) = if self.state.modifiers.shift && delta_x == 0.0 { (delta_y, 0.0) } else { (delta_x, delta_y) };
+            if delta_y != 0.0 && scroll.is_scrollable(ScrollAxis::Vertical) {
+               self.state.scrollable.scroll(delta_y, scroll.bounds, scroll.content_bounds());
+               event_status = event::Status::Captured;
+            }
+            if delta_x != 0.0 && scroll.is_scrollable(ScrollAxis::Horizontal) {
+               self.state.scroll_x = (scroll.offset.x - delta_x).max(0.0).min(scroll.max_offset_x());
+               event_status = event::Status::Captured;
+            }
+         }
+         Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::Enter, .. }) | Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::NumpadEnter, .. }) if self.state.is_focused && self.state.editing.is_none() => {
+            if let Some(row) = self.state.selected_row {
+               self.activate_row(row, messages);
+               event_status = event::Status::Captured;
+            }
+         }
+         Event::Keyboard(keyboard::Event::KeyPressed { key_code: keyboard::KeyCode::C, modifiers }) if modifiers.is_command_pressed() && self.state.is_focused && self.state.editing.is_none() => {
+            // the selected rows are copied in the order shown
+            let rows: Vec<usize> = self.state.rows.iter().copied().filter(|row| self.state.selection.contains(row)).collect();
+            if let (Some(on_copy), false) = (&self.on_copy, rows.is_empty()) {
+               if let Ok(text) = export::export(&*self.data, &rows, &self.columns, ExportFormat::Tsv) {
+                  messages.push(on_copy(text));
+                  event_status = event::Status::Captured;
+               }
+            }
+         }
+         Event::Keyboard(keyboard::Event::KeyPressed { key_code, modifiers }) if self.state.is_focused && self.state.editing.is_none() => {
+            let is_navigated = self.navigate_tree(key_code, messages) || self.navigate(key_code, modifiers, &scroll, messages);
+            event_status = if is_navigated { event::Status::Captured } else { event::Status::Ignored };
+         }
+         _ => {}
+      }
+
+      event_status
+   }
+}
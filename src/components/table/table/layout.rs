@@ -0,0 +1,280 @@
+use super::{
+   formatted_sortable_column, pagination, sort_priority, tree_indent, CellKind, Footer, FooterButton, Line, PageButton, Renderer, Table, TableColumn,
+   TableData, TableSource, TextOverflow, PROGRESS_BAR_WIDTH, VIRTUALIZED_SAMPLE_SIZE,
+};
+use iced_native::{layout::{Limits, Node}, text_input, Layout, Length, Point, Rectangle, Size, Widget};
+use serde_json::Value;
+use std::ops::Range;
+
+impl<'a, T, Message, Renderer> Table<'a, T, Message, Renderer>
+where
+   T: TableData,
+   Renderer: self::Renderer,
+{
+   pub(super) fn measured_records(&self) -> impl Iterator<Item = &T> {
+      let (step, count) = if self.is_virtualized {
+         ((self.data.len() / VIRTUALIZED_SAMPLE_SIZE).max(1), VIRTUALIZED_SAMPLE_SIZE)
+      } else {
+         (1, self.data.len())
+      };
+      let data: &dyn TableSource<T> = &*self.data;
+      (0..data.len()).step_by(step).take(count).filter_map(move |row| data.get(row))
+   }
+
+   pub(super) fn measure_cell(&self, renderer: &Renderer, column: &TableColumn, value: &Value, text_size: u16) -> Size {
+      if let Some(view) = self.cell_views.get(&column.name) {
+         return view(value).layout(renderer, &Limits::new(Size::ZERO, Size::new(f32::INFINITY, f32::INFINITY))).size();
+      }
+      match column.kind.format(value) {
+         Some(content) => {
+            let (width, height) = renderer.measure(&content, text_size, self.font, Size::new(f32::INFINITY, f32::INFINITY));
+            Size::new(width, height)
+         }
+         None => match column.kind {
+            CellKind::ProgressBar => Size::new(PROGRESS_BAR_WIDTH, f32::from(text_size)),
+            _ => Size::new(f32::from(text_size), f32::from(text_size)),
+         },
+      }
+   }
+
+   /// Measures the items of the pagination footer: its buttons, then the range of rows shown.
+   pub(super) fn footer_items(&self, renderer: &Renderer, text_size: u16) -> Vec<(Option<PageButton>, Size)> {
+      let padding = f32::from(self.padding);
+      let measure = |content: &str| {
+         let (width, height) = renderer.measure(content, text_size, self.font, Size::new(f32::INFINITY, f32::INFINITY));
+         Size::new(width, height).pad(padding)
+      };
+      let page_count = self.state.page_count();
+      let mut items: Vec<(Option<PageButton>, Size)> = PageButton::all(&self.page_sizes, page_count)
+         .into_iter()
+         .map(|button| (Some(button), measure(&button.widest_label(page_count))))
+         .collect();
+      let row_count = self.state.lines.len();
+      let range = pagination::range_text(0, row_count.max(1), row_count).replace(|c: char| c.is_ascii_digit(), "8");
+      items.push((None, measure(&format!("{}  ", range))));
+      items
+   }
+
+   /// Returns the footer of a paginated table as the renderer draws it.
+   pub(super) fn footer(&self, layout: Layout<'_>) -> Option<Footer> {
+      let footer_layout = layout.children().nth(4)?;
+      let (page, page_size, page_count) = (self.state.page(), self.state.page_size, self.state.page_count());
+      let mut items = footer_layout.children();
+      let buttons = PageButton::all(&self.page_sizes, page_count)
+         .into_iter()
+         .zip(&mut items)
+         .map(|(button, layout)| FooterButton {
+            label: button.label(page, page_count),
+            bounds: layout.bounds(),
+            is_active: button.is_active(page, page_size, page_count),
+            is_enabled: button.is_enabled(page, page_size, page_count),
+         })
+         .collect();
+      let range = (pagination::range_text(page, page_size, self.state.lines.len()), items.next()?.bounds());
+      Some(Footer { bounds: footer_layout.bounds(), range, buttons })
+   }
+
+   /// Returns the page positions of the rows in view in a body of the given size.
+   fn shown_rows(&self, body_size: Size, row_height: f32) -> Range<usize> {
+      let rows = self.state.page_lines().len();
+      if row_height <= 0.0 {
+         return 0..0;
+      }
+      let content = Size::new(body_size.width, row_height * rows as f32);
+      let offset = self.state.scrollable.offset(Rectangle::new(Point::ORIGIN, body_size), Rectangle::new(Point::ORIGIN, content)) as f32;
+      let first = (offset / row_height).floor() as usize;
+      let last = ((offset + body_size.height) / row_height).ceil() as usize;
+      first.min(rows)..last.min(rows)
+   }
+
+   /// Lays out a row like the template, with the content of its cells drawn with a view.
+   fn row_with_views(&self, renderer: &Renderer, template: &Node, position: usize, padding: f32) -> Node {
+      let record = match self.state.page_lines()[position] {
+         Line::Record(row) => self.data.get(row),
+         Line::Group(_) => None,
+      };
+      let cells = self
+         .columns
+         .iter()
+         .zip(template.children())
+         .map(|(column, cell)| {
+            let value = record.and_then(|record| record.get_field_value(&column.name).ok());
+            match (self.cell_views.get(&column.name), value) {
+               (Some(view), Some(value)) => {
+                  let cell_size = cell.size();
+                  let mut content = view(&value).layout(renderer, &Limits::new(Size::ZERO, Size::new((cell_size.width - padding * 2.0).max(0.0), cell_size.height)));
+                  content.move_to(Point::new(padding, (cell_size.height - content.size().height) / 2.0));
+                  let mut node = Node::with_children(cell_size, vec![content]);
+                  node.move_to(cell.bounds().position());
+                  node
+               }
+               _ => cell.clone(),
+            }
+         })
+         .collect();
+      Node::with_children(template.size(), cells)
+   }
+
+   /// Lays out the header, the divider, the visible rows and the footer.
+   pub(super) fn layout_table(&self, renderer: &Renderer, limits: &Limits) -> Node {
+      let padding = f32::from(self.padding);
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let mut limits = limits.width(self.width).height(self.height);
+      if let Some(max_height) = self.max_height {
+         limits = limits.max_height(max_height);
+      }
+      let mut max_cols_size: Vec<Size> = Vec::with_capacity(self.columns.len());
+
+      for column in self.columns.iter() {
+         let (width, height) = renderer.measure(&formatted_sortable_column(column.to_string(), column.order, sort_priority(&self.state.sort_keys, &column.name)), text_size, self.font, Size::new(f32::INFINITY, f32::INFINITY),);
+         let size = {
+            let intrinsic = Size::new(width+f32::from(text_size), height);
+            intrinsic.pad(padding)
+         };
+         max_cols_size.push(size);
+      }
+      let mut row_height: f32 = 0.0;
+      for record in self.measured_records() {
+         for (idx, column) in self.columns.iter().enumerate() {
+            if let Ok(value) = record.get_field_value(&column.name) {
+               let Size { width, height } = self.measure_cell(renderer, column, &value, text_size);
+               let size = {
+                  let intrinsic = Size::new(if let Some(max_width) = self.column_max_width {width.min(max_width)} else {width}, height);
+                  intrinsic.pad(padding)
+               };
+               if let Some(max_size) = max_cols_size.get_mut(idx) {
+                  max_size.width = max_size.width.max(size.width);
+               }
+               row_height = row_height.max(size.height);
+            }
+         }
+      }
+      // the first column of a tree also holds the indentation and chevrons
+      if let (Some(depth), Some(size)) = (self.state.tree.values().map(|node| node.depth).max(), max_cols_size.first_mut()) {
+         size.width += tree_indent(depth, text_size);
+      }
+      // widths set by resizing win over those of the columns
+      for (column, size) in self.columns.iter().zip(max_cols_size.iter_mut()) {
+         size.width = match (self.state.column_layout.width(&column.name), column.width) {
+            (Some(width), _) => width,
+            (None, Some(Length::Units(units))) => f32::from(units),
+            (None, _) => size.width,
+         };
+         size.width = column.clamp_width(size.width);
+      }
+      // filling columns share the space left in a table that is not shrinking
+      let available = limits.max().width - 2.0;
+      let used: f32 = max_cols_size.iter().map(|size| size.width).sum();
+      let fill_portion = |column: &TableColumn| column.fill_portion().filter(|_| self.state.column_layout.width(&column.name).is_none());
+      let portions: u32 = self.columns.iter().filter_map(fill_portion).map(u32::from).sum();
+      if self.width != Length::Shrink && available.is_finite() && available > used && portions > 0 {
+         let extra = available - used;
+         for (column, size) in self.columns.iter().zip(max_cols_size.iter_mut()) {
+            if let Some(portion) = fill_portion(column) {
+               size.width = column.clamp_width(size.width + extra * f32::from(portion) / portions as f32);
+            }
+         }
+      }
+      if let Some(fixed) = self.row_height {
+         row_height = f32::from(fixed);
+      } else {
+         // wrapped cells grow the rows to fit their lines in the final width of their column
+         for record in self.measured_records() {
+            for (column, size) in self.columns.iter().zip(max_cols_size.iter()) {
+               if column.overflow != TextOverflow::Wrap || self.cell_views.contains_key(&column.name) {
+                  continue;
+               }
+               if let Some(content) = record.get_field_value(&column.name).ok().and_then(|value| column.kind.format(&value)) {
+                  let (_, height) = renderer.measure(&content, text_size, self.font, Size::new((size.width - padding * 2.0).max(0.0), f32::INFINITY));
+                  row_height = row_height.max(height + padding * 2.0);
+               }
+            }
+         }
+      }
+      if row_height == 0.0 {
+         row_height = f32::from(text_size) + padding * 2.0;
+      }
+
+      let mut header_nodes = Vec::with_capacity(max_cols_size.len());
+      let mut row_nodes = Vec::with_capacity(max_cols_size.len());
+      let mut header_size = Size::ZERO;
+      for size in max_cols_size.iter() {
+         let mut node = if self.header_filters {
+            let mut input = Self::filter_input(&mut text_input::State::new(), "", text_size, self.padding, self.font).layout(renderer, &Limits::new(Size::ZERO, Size::new(size.width, f32::INFINITY)));
+            input.move_to(Point::new(0.0, size.height));
+            Node::with_children(Size::new(size.width, size.height + input.size().height), vec![input])
+         } else {
+            Node::new(*size)
+         };
+         node.move_to(Point::new(header_size.width, 0.0));
+         header_size.height = header_size.height.max(node.size().height);
+         header_nodes.push(node);
+         let mut cell = Node::new(Size::new(size.width, row_height));
+         cell.move_to(Point::new(header_size.width, 0.0));
+         row_nodes.push(cell);
+         header_size.width += size.width;
+      }
+
+      let body_height = row_height * self.state.page_lines().len() as f32;
+      let footer_items = if self.state.is_paginated { self.footer_items(renderer, text_size) } else { Vec::new() };
+      let footer_height = footer_items.iter().map(|(_, size)| size.height).fold(0.0, f32::max);
+      let footer_width = footer_items.iter().map(|(_, size)| size.width).sum::<f32>() + padding * 2.0;
+      let totals_height = if self.totals_row { row_height } else { 0.0 };
+      let size = limits.resolve(Size::new(header_size.width.max(footer_width) + 2.0, header_size.height + body_height + totals_height + footer_height + 3.0));
+
+      let mut header = Node::with_children(header_size, header_nodes);
+      header.move_to(Point::new(1.0, 1.0));
+
+      let mut divider = Node::new(Size::new(size.width, 1.0));
+      divider.move_to(Point::new(0.0, header_size.height + 1.0));
+
+      // the body holds a template row, the rows themselves are placed from the
+      // scroll offset when drawing and handling events
+      let row = Node::with_children(Size::new(header_size.width, row_height), row_nodes);
+      let body_size = Size::new((size.width - 2.0).max(0.0), (size.height - header_size.height - totals_height - footer_height - 3.0).max(0.0));
+      let mut body_rows = vec![row];
+      if !self.cell_views.is_empty() {
+         // the cells drawn with a view get the layout of their own content,
+         // for each row in view after the template
+         let shown = self.shown_rows(body_size, row_height);
+         self.state.shown.set((shown.start, shown.end));
+         let rows: Vec<Node> = shown.map(|position| self.row_with_views(renderer, &body_rows[0], position, padding)).collect();
+         body_rows.extend(rows);
+      }
+      let mut body = Node::with_children(body_size, body_rows);
+      body.move_to(Point::new(1.0, header_size.height + 2.0));
+
+      // the totals row stays below the scrolled body
+      let mut totals = Node::new(Size::new((size.width - 2.0).max(0.0), totals_height));
+      totals.move_to(Point::new(1.0, size.height - footer_height - totals_height - 1.0));
+
+      if footer_items.is_empty() {
+         return Node::with_children(size, vec![header, divider, body, totals]);
+      }
+      // the page sizes and the range of rows sit on the left, the page buttons on the right
+      let mut footer_nodes: Vec<Node> = Vec::with_capacity(footer_items.len());
+      let (mut left, mut right) = (padding, size.width - padding);
+      for (button, item_size) in footer_items.iter().rev() {
+         let mut node = Node::new(*item_size);
+         match button {
+            Some(PageButton::Size(_)) | None => {}
+            Some(_) => {
+               right -= item_size.width;
+               node.move_to(Point::new(right, 0.0));
+            }
+         }
+         footer_nodes.push(node);
+      }
+      footer_nodes.reverse();
+      for ((button, item_size), node) in footer_items.iter().zip(footer_nodes.iter_mut()) {
+         if let Some(PageButton::Size(_)) | None = button {
+            node.move_to(Point::new(left, 0.0));
+            left += item_size.width;
+         }
+      }
+      let mut footer = Node::with_children(Size::new(size.width, footer_height), footer_nodes);
+      footer.move_to(Point::new(0.0, size.height - footer_height));
+
+      Node::with_children(size, vec![header, divider, body, totals, footer])
+   }
+}
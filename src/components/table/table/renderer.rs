@@ -0,0 +1,800 @@
+use super::{
+   cell, chevron_bounds, fit_text, header_label, tree_indent, CellKind, CellView, ColumnInteraction, Footer, Line, RowStyle, Scroll, ScrollAxis, State,
+   TableColumn, TableData, TableSource, PROGRESS_BAR_WIDTH,
+};
+use crate::components::{icon::ICON_FONT, toggler};
+use crate::styles::table::StyleSheet;
+use iced_graphics::Primitive;
+use iced_native::{
+   column, container, mouse, row, text, text_input, Background, Color, HorizontalAlignment, Layout, Point, Rectangle, Vector, VerticalAlignment,
+};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// What [`Renderer::draw`] needs from a table to draw it.
+pub struct Drawing<'a, 'b, T: TableData, Message, Renderer: self::Renderer> {
+   pub columns: &'a [TableColumn],
+   pub data: &'a dyn TableSource<T>,
+   pub cell_views: &'a HashMap<String, CellView<'b, Message, Renderer>>,
+   pub row_style: Option<&'a RowStyle<T>>,
+   /// The filter inputs of the header, already drawn.
+   pub filter_inputs: Vec<Renderer::Output>,
+   pub footer: Option<Footer>,
+   pub column_interaction: ColumnInteraction,
+   pub state: &'a State,
+   pub pinned_columns: usize,
+   pub is_orderable: bool,
+   pub text_size: u16,
+   pub padding: u16,
+   pub font: Renderer::Font,
+   pub style: &'a <Renderer as self::Renderer>::Style,
+}
+
+pub trait Renderer: text::Renderer + text_input::Renderer + toggler::Renderer + container::Renderer + column::Renderer + row::Renderer + Sized {
+   type Style: Default;
+   const DEFAULT_PADDING: u16;
+   const DEFAULT_HEADER_SPACING: u16;
+
+   /// Draws the table, its header, rows and footer as laid out by the widget.
+   fn draw<T: TableData, Message>(
+      &mut self,
+      defaults: &Self::Defaults,
+      layout: Layout<'_>,
+      cursor_position: Point,
+      viewport: &Rectangle,
+      table: Drawing<'_, '_, T, Message, Self>,
+   ) -> Self::Output;
+
+   /// Draws the editor of a cell around its `content`, with an optional validation error.
+   #[allow(clippy::too_many_arguments)]
+   fn draw_editor(
+      &mut self,
+      bounds: Rectangle,
+      content: Self::Output,
+      error: Option<(&str, Rectangle)>,
+      text_size: u16,
+      padding: u16,
+      font: Self::Font,
+      style: &<Self as self::Renderer>::Style,
+   ) -> Self::Output;
+
+   /// Draws a tooltip showing `text` in `bounds`.
+   fn draw_tooltip(&mut self, bounds: Rectangle, text: &str, text_size: u16, padding: u16, font: Self::Font, style: &<Self as self::Renderer>::Style) -> Self::Output;
+
+   /// Draws a context menu in `bounds`, with its items as their bounds, label and whether they can be chosen.
+   #[allow(clippy::too_many_arguments)]
+   fn draw_menu(&mut self, bounds: Rectangle, items: &[(Rectangle, &str, bool)], hovered: Option<usize>, text_size: u16, padding: u16, font: Self::Font, style: &<Self as self::Renderer>::Style) -> Self::Output;
+}
+
+impl Renderer for iced_wgpu::Renderer
+where
+{
+   type Style = Box<dyn StyleSheet>;
+   const DEFAULT_PADDING: u16 = 6;
+   const DEFAULT_HEADER_SPACING: u16 = 1;
+
+   fn draw<T: TableData, Message>(
+      &mut self,
+      defaults: &Self::Defaults,
+      layout: Layout<'_>,
+      cursor_position: Point,
+      viewport: &Rectangle,
+      table: Drawing<'_, '_, T, Message, Self>,
+   ) -> Self::Output {
+      let Drawing { columns, data, cell_views, row_style, filter_inputs, footer, column_interaction, state, pinned_columns, is_orderable, text_size, padding, font, style } = table;
+      let mut children = layout.children();
+      let header_layout = children.next().unwrap();
+      let divider_bounds = children.next().unwrap().bounds();
+      let body_layout = children.next().unwrap();
+      let scroll = Scroll::new(body_layout, &state.scrollable, state.scroll_x, state.page_lines().len(), pinned_columns);
+      let header_bounds = Rectangle {
+         width: scroll.bounds.width,
+         ..header_layout.bounds()
+      };
+      let header_mouse_over = header_bounds.contains(cursor_position);
+      let styling = if header_mouse_over {
+         style.header_hoverd()
+      } else {
+         style.active()
+      };
+
+      let background = Primitive::Quad {
+         bounds: layout.bounds(),
+         background: styling.background,
+         border_color: styling.border_color,
+         border_width: styling.border_width,
+         border_radius: styling.border_radius,
+      };
+      let divider = Primitive::Quad {
+         bounds: divider_bounds,
+         background: Background::Color(Color::TRANSPARENT),
+         border_color: styling.border_color,
+         border_width: styling.border_width,
+         border_radius: 0.0,
+      };
+      let header_background = Primitive::Quad {
+         bounds: header_bounds,
+         background: styling.header_background,
+         border_color: Color::TRANSPARENT,
+         border_width: 0.0,
+         border_radius: styling.border_radius,
+      };
+
+      let mut filter_inputs = filter_inputs.into_iter();
+      let header_section = Primitive::Group {
+         primitives: columns
+            .iter()
+            .zip(header_layout.children())
+            .enumerate()
+            .map(|(idx, (column, layout))| {
+               let is_pinned = idx < pinned_columns;
+               let cell_bounds = scroll.header_cell(layout.bounds(), is_pinned);
+               let region = if is_pinned { scroll.pinned_bounds(header_bounds) } else { scroll.scrolled_bounds(header_bounds) };
+               // the label takes the space above the filter input
+               let (bounds, filter_input) = match (layout.children().next(), filter_inputs.next()) {
+                  (Some(input_layout), Some((input, _))) => (
+                     Rectangle {
+                        height: input_layout.bounds().y - layout.bounds().y,
+                        ..cell_bounds
+                     },
+                     Primitive::Translate {
+                        translation: cell_bounds.position() - layout.bounds().position(),
+                        content: Box::new(input),
+                     },
+                  ),
+                  _ => (cell_bounds, Primitive::None),
+               };
+               let (label, _) = header_label(self, column, &state.sort_keys, bounds.width, text_size, padding, font);
+               let text = Primitive::Text {
+                  content: label,
+                  size: f32::from(text_size),
+                  font,
+                  color: styling.text_color,
+                  bounds: Rectangle {
+                     x: text_x(bounds, f32::from(padding), column.alignment()),
+                     y: bounds.center_y(),
+                     ..bounds
+                  },
+                  horizontal_alignment: column.alignment(),
+                  vertical_alignment: VerticalAlignment::Center,
+               };
+               clip(Primitive::Group { primitives: vec![text, filter_input] }, cell_bounds, region)
+            })
+            .collect(),
+      };
+
+      let mut row_layouts = body_layout.children();
+      let row_layout = row_layouts.next().unwrap();
+      // the rows laid out with their cell views follow the template
+      let row_layouts: Vec<Layout<'_>> = row_layouts.collect();
+      let (first_shown, _) = state.shown.get();
+      let rows = scroll.visible_rows(viewport, state.page_lines().len());
+      let pinned_region = scroll.pinned_bounds(scroll.bounds);
+      let scrolled_region = scroll.scrolled_bounds(scroll.bounds);
+      let editing_cell = state.editing_cell();
+      let row_styles = style.table_row();
+      let is_mouse_over_scrollbar = [ScrollAxis::Vertical, ScrollAxis::Horizontal].iter().any(|&axis| scroll.scrollbar(axis).map_or(false, |scrollbar| scrollbar.bounds.contains(cursor_position)));
+      let hovered_row = if is_mouse_over_scrollbar { None } else { scroll.row_at(cursor_position, state.page_lines().len()) };
+      let mut body_records = Vec::with_capacity(rows.len());
+      for (position, &line) in state.page_lines().iter().enumerate().skip(rows.start).take(rows.len()) {
+         let row_bounds = Rectangle {
+            x: scroll.bounds.x,
+            width: scroll.bounds.width.min(scroll.content.width),
+            ..scroll.cell(row_layout.bounds(), true, position)
+         };
+         let idx = match line {
+            Line::Record(idx) => idx,
+            Line::Group(group) => {
+               let group = &state.groups[group];
+               let background = Primitive::Quad {
+                  bounds: row_bounds,
+                  background: styling.header_background,
+                  border_color: Color::TRANSPARENT,
+                  border_width: 0.0,
+                  border_radius: 0.0,
+               };
+               let marker = if state.is_group_collapsed(&group.value) { "▶" } else { "▼" };
+               let label = Primitive::Text {
+                  content: format!("{} {} ({})", marker, group.label, group.rows.len()),
+                  size: f32::from(text_size),
+                  font,
+                  color: styling.text_color,
+                  bounds: Rectangle {
+                     x: row_bounds.x + f32::from(padding),
+                     y: row_bounds.center_y(),
+                     ..row_bounds
+                  },
+                  horizontal_alignment: HorizontalAlignment::Left,
+                  vertical_alignment: VerticalAlignment::Center,
+               };
+               let mut primitives = vec![background, label];
+               primitives.extend(aggregate_cells(&group.aggregates, columns, row_layout, &scroll, row_bounds.y, pinned_columns, text_size, padding, font, styling.text_color));
+               body_records.push(Primitive::Group { primitives });
+               continue;
+            }
+         };
+         // records still loading are drawn as placeholders
+         let record = data.get(idx);
+         let mut record_cells = Vec::with_capacity(columns.len() + 3);
+         let (background, mut text_color) = match row_style.zip(record).and_then(|(row_style, record)| row_style(record)) {
+            Some(record_style) => (record_style.background, record_style.text_color),
+            None if position % 2 == 1 => (styling.striped_background.or(styling.table_row.background), styling.table_row.text_color),
+            None => (styling.table_row.background, styling.table_row.text_color),
+         };
+         let highlight = if state.selection.contains(&idx) {
+            Some(row_styles.selected())
+         } else if hovered_row == Some(position) {
+            Some(row_styles.hovered())
+         } else {
+            None
+         };
+         if let Some(highlight) = &highlight {
+            text_color = highlight.text_color;
+         }
+         for background in background.into_iter().chain(highlight.and_then(|highlight| highlight.background)) {
+            record_cells.push(Primitive::Quad {
+               bounds: row_bounds,
+               background,
+               border_color: Color::TRANSPARENT,
+               border_width: 0.0,
+               border_radius: 0.0,
+            });
+         }
+         if let Some(color) = styling.row_divider {
+            record_cells.push(Primitive::Quad {
+               bounds: Rectangle {
+                  y: row_bounds.y + row_bounds.height - 1.0,
+                  height: 1.0,
+                  ..row_bounds
+               },
+               background: Background::Color(color),
+               border_color: Color::TRANSPARENT,
+               border_width: 0.0,
+               border_radius: 0.0,
+            });
+         }
+         let cell_layouts = position.checked_sub(first_shown).and_then(|shown| row_layouts.get(shown)).unwrap_or(&row_layout).children();
+         for (column_idx, (column, cell_layout)) in columns.iter().zip(cell_layouts).enumerate() {
+            // the cell being edited is covered by its editor
+            if editing_cell == Some((idx, column.name.as_str())) {
+               continue;
+            }
+            let is_pinned = column_idx < pinned_columns;
+            let bounds = scroll.cell(cell_layout.bounds(), is_pinned, position);
+            let value = match record.map(|record| record.get_field_value(&column.name)) {
+               Some(Ok(value)) => value,
+               Some(Err(_)) => continue,
+               None => {
+                  record_cells.push(clip(skeleton_primitive(bounds, text_size, padding, styling.skeleton_color), bounds, if is_pinned { pinned_region } else { scrolled_region }));
+                  continue;
+               }
+            };
+            // the first cell of a tree row is indented by depth after the chevron of its node
+            let (content_bounds, chevron) = match state.tree.get(&idx).filter(|_| column_idx == 0) {
+               Some(node) => {
+                  let indent = tree_indent(node.depth, text_size);
+                  let chevron = if node.is_expandable {
+                     Primitive::Text {
+                        content: String::from(if node.is_expanded { "▼" } else { "▶" }),
+                        size: f32::from(text_size) * 0.8,
+                        font,
+                        color: text_color,
+                        bounds: Rectangle {
+                           x: chevron_bounds(bounds, node.depth, text_size, padding).center_x(),
+                           y: bounds.center_y(),
+                           ..bounds
+                        },
+                        horizontal_alignment: HorizontalAlignment::Center,
+                        vertical_alignment: VerticalAlignment::Center,
+                     }
+                  } else {
+                     Primitive::None
+                  };
+                  (Rectangle { x: bounds.x + indent, width: (bounds.width - indent).max(0.0), ..bounds }, chevron)
+               }
+               None => (bounds, Primitive::None),
+            };
+            let content = match (cell_views.get(&column.name), cell_layout.children().next()) {
+               (Some(view), Some(content_layout)) => {
+                  let translation = Vector::new(content_bounds.x - cell_layout.bounds().x, content_bounds.y - cell_layout.bounds().y);
+                  let (content, _) = view(&value).draw(self, defaults, content_layout, cursor_position - translation, viewport);
+                  Primitive::Translate { translation, content: Box::new(content) }
+               }
+               _ => {
+                  let content = column.kind.format(&value).map(|content| fit_text(self, column.overflow, content, content_bounds.width - f32::from(padding) * 2.0, text_size, font).0);
+                  cell_primitive(column, content, &value, content_bounds, text_size, padding, font, text_color, &styling)
+               }
+            };
+            record_cells.push(clip(Primitive::Group { primitives: vec![chevron, content] }, bounds, if is_pinned { pinned_region } else { scrolled_region }));
+         }
+         let record = Primitive::Group{ primitives: record_cells };
+         body_records.push(record);
+      }
+      // the dividers between columns run from the header down to the last row
+      let bottom = scroll.bounds.y + scroll.bounds.height.min(scroll.content.height - scroll.offset.y);
+      let column_dividers = match styling.column_divider {
+         Some(color) => Primitive::Group {
+            primitives: row_layout
+               .children()
+               .enumerate()
+               .map(|(column_idx, cell_layout)| {
+                  let is_pinned = column_idx < pinned_columns;
+                  let cell_bounds = scroll.cell(cell_layout.bounds(), is_pinned, 0);
+                  let bounds = Rectangle {
+                     x: cell_bounds.x + cell_bounds.width - 1.0,
+                     y: header_bounds.y,
+                     width: 1.0,
+                     height: (bottom - header_bounds.y).max(0.0),
+                  };
+                  let divider = Primitive::Quad {
+                     bounds,
+                     background: Background::Color(color),
+                     border_color: Color::TRANSPARENT,
+                     border_width: 0.0,
+                     border_radius: 0.0,
+                  };
+                  let region = if is_pinned { scroll.pinned_bounds(scroll.bounds) } else { scroll.scrolled_bounds(scroll.bounds) };
+                  clip(divider, bounds, Rectangle { y: bounds.y, height: bounds.height, ..region })
+               })
+               .collect(),
+         },
+         None => Primitive::None,
+      };
+      let body_section = Primitive::Clip {
+         bounds: scroll.bounds,
+         content: Box::new(Primitive::Group{ primitives: body_records }),
+         offset: Vector::new(0, 0),
+      };
+      let totals_bounds = layout.children().nth(3).map(|totals| totals.bounds()).filter(|bounds| bounds.height > 0.0);
+      let totals_section = match totals_bounds {
+         Some(bounds) => {
+            let mut primitives = vec![
+               Primitive::Quad {
+                  bounds,
+                  background: styling.header_background,
+                  border_color: Color::TRANSPARENT,
+                  border_width: 0.0,
+                  border_radius: 0.0,
+               },
+               Primitive::Text {
+                  content: format!("Total ({})", state.rows.len()),
+                  size: f32::from(text_size),
+                  font,
+                  color: styling.text_color,
+                  bounds: Rectangle {
+                     x: bounds.x + f32::from(padding),
+                     y: bounds.center_y(),
+                     ..bounds
+                  },
+                  horizontal_alignment: HorizontalAlignment::Left,
+                  vertical_alignment: VerticalAlignment::Center,
+               },
+            ];
+            primitives.extend(aggregate_cells(&state.totals, columns, row_layout, &scroll, bounds.y, pinned_columns, text_size, padding, font, styling.text_color));
+            Primitive::Clip {
+               bounds,
+               content: Box::new(Primitive::Group { primitives }),
+               offset: Vector::new(0, 0),
+            }
+         }
+         None => Primitive::None,
+      };
+
+      let scrollbars = Primitive::Group {
+         primitives: [ScrollAxis::Vertical, ScrollAxis::Horizontal]
+            .iter()
+            .filter_map(|&axis| scroll.scrollbar(axis).map(|scrollbar| (axis, scrollbar)))
+            .map(|(axis, scrollbar)| {
+               let is_grabbed = state.scroller_grabbed_at.map_or(false, |(grabbed_axis, _)| grabbed_axis == axis);
+               let scrollbar_style = if is_grabbed {
+                  style.scrollbar_dragging()
+               } else if scrollbar.bounds.contains(cursor_position) {
+                  style.scrollbar_hovered()
+               } else {
+                  style.scrollbar()
+               };
+               let track = Primitive::Quad {
+                  bounds: scrollbar.bounds,
+                  background: scrollbar_style.background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+                  border_color: scrollbar_style.border_color,
+                  border_width: scrollbar_style.border_width,
+                  border_radius: scrollbar_style.border_radius,
+               };
+               let scroller = Primitive::Quad {
+                  bounds: scrollbar.scroller,
+                  background: Background::Color(scrollbar_style.scroller.color),
+                  border_color: scrollbar_style.scroller.border_color,
+                  border_width: scrollbar_style.scroller.border_width,
+                  border_radius: scrollbar_style.scroller.border_radius,
+               };
+               Primitive::Group { primitives: vec![track, scroller] }
+            })
+            .collect(),
+      };
+
+      let hovered_button = footer.as_ref().and_then(|footer| footer.buttons.iter().find(|button| button.is_enabled && button.bounds.contains(cursor_position)).map(|button| button.bounds));
+      let footer_section = match footer {
+         Some(footer) => {
+            let hovered_style = row_styles.hovered();
+            let text = |content: String, bounds: Rectangle, color: Color| Primitive::Text {
+               content,
+               size: f32::from(text_size),
+               font,
+               color,
+               bounds: Rectangle {
+                  x: bounds.center_x(),
+                  y: bounds.center_y(),
+                  ..bounds
+               },
+               horizontal_alignment: HorizontalAlignment::Center,
+               vertical_alignment: VerticalAlignment::Center,
+            };
+            let mut primitives = vec![
+               Primitive::Quad {
+                  bounds: footer.bounds,
+                  background: styling.header_background,
+                  border_color: Color::TRANSPARENT,
+                  border_width: 0.0,
+                  border_radius: 0.0,
+               },
+               Primitive::Quad {
+                  bounds: Rectangle { height: 1.0, ..footer.bounds },
+                  background: Background::Color(styling.border_color),
+                  border_color: Color::TRANSPARENT,
+                  border_width: 0.0,
+                  border_radius: 0.0,
+               },
+               text(footer.range.0, footer.range.1, styling.text_color),
+            ];
+            for button in footer.buttons {
+               let background = if button.is_active {
+                  Some(Background::Color(styling.accent_color))
+               } else if hovered_button == Some(button.bounds) {
+                  hovered_style.background
+               } else {
+                  None
+               };
+               if let Some(background) = background {
+                  primitives.push(Primitive::Quad {
+                     bounds: button.bounds,
+                     background,
+                     border_color: Color::TRANSPARENT,
+                     border_width: 0.0,
+                     border_radius: 2.0,
+                  });
+               }
+               let color = if button.is_active {
+                  Color::WHITE
+               } else if button.is_enabled {
+                  styling.text_color
+               } else {
+                  Color { a: styling.text_color.a * 0.4, ..styling.text_color }
+               };
+               primitives.push(text(button.label, button.bounds, color));
+            }
+            Primitive::Group { primitives }
+         }
+         None => Primitive::None,
+      };
+
+      let column_guide = match column_interaction {
+         ColumnInteraction::Resizing(x) | ColumnInteraction::Moving(x) => Primitive::Quad {
+            bounds: Rectangle {
+               x: x - 1.0,
+               y: header_bounds.y,
+               width: 2.0,
+               height: scroll.bounds.y + scroll.bounds.height - header_bounds.y,
+            },
+            background: Background::Color(styling.accent_color),
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_radius: 0.0,
+         },
+         _ => Primitive::None,
+      };
+
+      (
+         Primitive::Group{ primitives: vec![background, header_background, body_section, column_dividers, totals_section, divider, header_section, footer_section, scrollbars, column_guide] },
+         match column_interaction {
+            ColumnInteraction::ResizeHandle | ColumnInteraction::Resizing(_) => mouse::Interaction::ResizingHorizontally,
+            ColumnInteraction::Moving(_) => mouse::Interaction::Grabbing,
+            ColumnInteraction::Idle if (header_mouse_over && is_orderable && !is_mouse_over_scrollbar) || hovered_button.is_some() => mouse::Interaction::Pointer,
+            ColumnInteraction::Idle => mouse::Interaction::default(),
+         },
+      )
+   }
+
+   fn draw_editor(
+      &mut self,
+      bounds: Rectangle,
+      (content, mouse_interaction): Self::Output,
+      error: Option<(&str, Rectangle)>,
+      text_size: u16,
+      padding: u16,
+      font: Self::Font,
+      style: &<Self as self::Renderer>::Style,
+   ) -> Self::Output {
+      let styling = style.active();
+      let background = Primitive::Quad {
+         bounds,
+         background: styling.background,
+         border_color: if error.is_some() { styling.error_color } else { styling.accent_color },
+         border_width: 1.0,
+         border_radius: 2.0,
+      };
+      let error = match error {
+         Some((message, bounds)) => Primitive::Group {
+            primitives: vec![
+               Primitive::Quad {
+                  bounds,
+                  background: Background::Color(styling.error_color),
+                  border_color: Color::TRANSPARENT,
+                  border_width: 0.0,
+                  border_radius: 2.0,
+               },
+               Primitive::Text {
+                  content: message.to_string(),
+                  size: f32::from(text_size),
+                  font,
+                  color: Color::WHITE,
+                  bounds: Rectangle {
+                     x: bounds.x + f32::from(padding),
+                     y: bounds.center_y(),
+                     ..bounds
+                  },
+                  horizontal_alignment: HorizontalAlignment::Left,
+                  vertical_alignment: VerticalAlignment::Center,
+               },
+            ],
+         },
+         None => Primitive::None,
+      };
+
+      (Primitive::Group { primitives: vec![background, content, error] }, mouse_interaction)
+   }
+
+   fn draw_tooltip(&mut self, bounds: Rectangle, text: &str, text_size: u16, padding: u16, font: Self::Font, style: &<Self as self::Renderer>::Style) -> Self::Output {
+      let styling = style.active();
+      let background = Primitive::Quad {
+         bounds,
+         background: styling.tooltip_background,
+         border_color: styling.border_color,
+         border_width: 1.0,
+         border_radius: 2.0,
+      };
+      let text = Primitive::Text {
+         content: text.to_string(),
+         size: f32::from(text_size),
+         font,
+         color: styling.tooltip_text_color,
+         bounds: Rectangle {
+            x: bounds.x + f32::from(padding),
+            y: bounds.y + f32::from(padding),
+            width: bounds.width - f32::from(padding) * 2.0,
+            height: bounds.height - f32::from(padding) * 2.0,
+         },
+         horizontal_alignment: HorizontalAlignment::Left,
+         vertical_alignment: VerticalAlignment::Top,
+      };
+
+      (Primitive::Group { primitives: vec![background, text] }, mouse::Interaction::default())
+   }
+
+   fn draw_menu(&mut self, bounds: Rectangle, items: &[(Rectangle, &str, bool)], hovered: Option<usize>, text_size: u16, padding: u16, font: Self::Font, style: &<Self as self::Renderer>::Style) -> Self::Output {
+      let styling = style.active();
+      let mut primitives = vec![Primitive::Quad {
+         bounds,
+         background: styling.background,
+         border_color: styling.border_color,
+         border_width: 1.0,
+         border_radius: 2.0,
+      }];
+      let hovered = hovered.filter(|&idx| items.get(idx).map_or(false, |&(_, _, is_enabled)| is_enabled));
+      if let Some(&(item_bounds, ..)) = hovered.and_then(|idx| items.get(idx)) {
+         primitives.push(Primitive::Quad {
+            bounds: item_bounds,
+            background: style.table_row().hovered().background.unwrap_or(Background::Color(Color { a: 0.2, ..styling.accent_color })),
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_radius: 0.0,
+         });
+      }
+      for &(item_bounds, label, is_enabled) in items {
+         primitives.push(Primitive::Text {
+            content: label.to_string(),
+            size: f32::from(text_size),
+            font,
+            // disabled items are greyed out
+            color: if is_enabled { styling.text_color } else { Color { a: styling.text_color.a * 0.4, ..styling.text_color } },
+            bounds: Rectangle {
+               x: item_bounds.x + f32::from(padding) * 2.0,
+               y: item_bounds.center_y(),
+               ..item_bounds
+            },
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+         });
+      }
+
+      let mouse_interaction = if hovered.is_some() { mouse::Interaction::Pointer } else { mouse::Interaction::default() };
+      (Primitive::Group { primitives }, mouse_interaction)
+   }
+}
+
+/// Draws a cell, from its text as fitted by [`fit_text`] when its kind shows one.
+#[allow(clippy::too_many_arguments)]
+fn cell_primitive(column: &TableColumn, content: Option<String>, value: &Value, bounds: Rectangle, text_size: u16, padding: u16, font: iced_native::Font, text_color: Color, styling: &crate::styles::table::Style) -> Primitive {
+   let kind = &column.kind;
+   let align = column.alignment();
+   let padding = f32::from(padding);
+   let text_size = f32::from(text_size);
+   let text = |content: String, font, color| Primitive::Text {
+      content,
+      size: text_size,
+      font,
+      color,
+      bounds: Rectangle {
+         x: text_x(bounds, padding, align),
+         y: bounds.center_y(),
+         width: (bounds.width - padding * 2.0).max(0.0),
+         ..bounds
+      },
+      horizontal_alignment: align,
+      vertical_alignment: VerticalAlignment::Center,
+   };
+
+   if let Some(content) = content {
+      return text(content, font, text_color);
+   }
+
+   match kind {
+      CellKind::Checkbox => {
+         let size = text_size.min(bounds.height - padding);
+         let bounds = Rectangle {
+            x: shape_x(bounds, padding, size, align),
+            y: bounds.center_y() - size / 2.0,
+            width: size,
+            height: size,
+         };
+         let is_checked = value.as_bool().unwrap_or(false);
+         let check_box = Primitive::Quad {
+            bounds,
+            background: Background::Color(if is_checked { styling.accent_color } else { Color::TRANSPARENT }),
+            border_color: if is_checked { styling.accent_color } else { text_color },
+            border_width: 1.0,
+            border_radius: 2.0,
+         };
+         let check_mark = if is_checked {
+            Primitive::Text {
+               content: crate::utils::Icons::Check.to_string(),
+               size: size * 0.8,
+               font: ICON_FONT,
+               color: Color::WHITE,
+               bounds: Rectangle {
+                  x: bounds.center_x(),
+                  y: bounds.center_y(),
+                  ..bounds
+               },
+               horizontal_alignment: HorizontalAlignment::Center,
+               vertical_alignment: VerticalAlignment::Center,
+            }
+         } else {
+            Primitive::None
+         };
+         Primitive::Group { primitives: vec![check_box, check_mark] }
+      }
+      CellKind::Icon { on, off } => {
+         match (value.as_bool().unwrap_or(false), off) {
+            (true, _) => text(on.to_string(), ICON_FONT, text_color),
+            (false, Some(off)) => text(off.to_string(), ICON_FONT, text_color),
+            (false, None) => Primitive::None,
+         }
+      }
+      CellKind::ProgressBar => {
+         let width = PROGRESS_BAR_WIDTH.min(bounds.width - padding * 2.0).max(0.0);
+         let track = Rectangle {
+            x: shape_x(bounds, padding, width, align),
+            y: bounds.center_y() - text_size / 4.0,
+            width,
+            height: text_size / 2.0,
+         };
+         let bar = Primitive::Quad {
+            bounds: track,
+            background: styling.header_background,
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_radius: track.height / 2.0,
+         };
+         let fill = Primitive::Quad {
+            bounds: Rectangle {
+               width: track.width * cell::progress(value),
+               ..track
+            },
+            background: Background::Color(styling.accent_color),
+            border_color: Color::TRANSPARENT,
+            border_width: 0.0,
+            border_radius: track.height / 2.0,
+         };
+         Primitive::Group { primitives: vec![bar, fill] }
+      }
+      _ => Primitive::None,
+   }
+}
+
+/// Draws aggregate texts in the cells of their columns, on the row starting at `y`.
+#[allow(clippy::too_many_arguments)]
+fn aggregate_cells(aggregates: &[(String, String)], columns: &[TableColumn], row_layout: Layout<'_>, scroll: &Scroll, y: f32, pinned_columns: usize, text_size: u16, padding: u16, font: iced_native::Font, color: Color) -> Vec<Primitive> {
+   columns
+      .iter()
+      .zip(row_layout.children())
+      .enumerate()
+      .filter_map(|(idx, (column, cell_layout))| {
+         let (_, text) = aggregates.iter().find(|(name, _)| *name == column.name)?;
+         let is_pinned = idx < pinned_columns;
+         let bounds = Rectangle { y, ..scroll.cell(cell_layout.bounds(), is_pinned, 0) };
+         let region = if is_pinned { scroll.pinned_bounds(scroll.bounds) } else { scroll.scrolled_bounds(scroll.bounds) };
+         let content = Primitive::Text {
+            content: text.clone(),
+            size: f32::from(text_size),
+            font,
+            color,
+            bounds: Rectangle {
+               x: bounds.x + f32::from(padding),
+               y: bounds.center_y(),
+               ..bounds
+            },
+            horizontal_alignment: HorizontalAlignment::Left,
+            vertical_alignment: VerticalAlignment::Center,
+         };
+         Some(clip(content, bounds, Rectangle { y: bounds.y, height: bounds.height, ..region }))
+      })
+      .collect()
+}
+
+/// Draws a bar standing for the text of a cell whose record is not loaded yet.
+fn skeleton_primitive(bounds: Rectangle, text_size: u16, padding: u16, color: Color) -> Primitive {
+   let padding = f32::from(padding);
+   let height = f32::from(text_size) / 2.0;
+   Primitive::Quad {
+      bounds: Rectangle {
+         x: bounds.x + padding,
+         y: bounds.center_y() - height / 2.0,
+         width: ((bounds.width - padding * 2.0) * 0.7).max(0.0),
+         height,
+      },
+      background: Background::Color(color),
+      border_color: Color::TRANSPARENT,
+      border_width: 0.0,
+      border_radius: height / 2.0,
+   }
+}
+
+/// Returns where text with the given alignment is anchored in a cell.
+fn text_x(bounds: Rectangle, padding: f32, align: HorizontalAlignment) -> f32 {
+   match align {
+      HorizontalAlignment::Left => bounds.x + padding,
+      HorizontalAlignment::Center => bounds.center_x(),
+      HorizontalAlignment::Right => bounds.x + bounds.width - padding,
+   }
+}
+
+/// Returns the left edge of a shape of the given width aligned in a cell.
+fn shape_x(bounds: Rectangle, padding: f32, width: f32, align: HorizontalAlignment) -> f32 {
+   match align {
+      HorizontalAlignment::Left => bounds.x + padding,
+      HorizontalAlignment::Center => bounds.center_x() - width / 2.0,
+      HorizontalAlignment::Right => bounds.x + bounds.width - padding - width,
+   }
+}
+
+/// Clips a cell to its own bounds within the pinned or scrolled region it is drawn in.
+fn clip(content: Primitive, bounds: Rectangle, region: Rectangle) -> Primitive {
+   match bounds.intersection(&region) {
+      Some(bounds) => Primitive::Clip {
+         bounds,
+         content: Box::new(content),
+         offset: Vector::new(0, 0),
+      },
+      None => Primitive::None,
+   }
+}
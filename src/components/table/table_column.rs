@@ -1,11 +1,112 @@
+use super::cell::CellKind;
+use iced_native::{HorizontalAlignment, Length};
+use serde::{Deserialize, Serialize};
 use smart_default::SmartDefault;
+use std::collections::BTreeMap;
 
-#[derive(Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct TableColumn {
     pub name: String,
     pub label: Option<String>,
     pub short_name: Option<String>,
     pub order: TableOrder,
+    pub kind: CellKind,
+    pub editable: bool,
+    /// Where the header and cells are aligned, on the right for numbers and on the left otherwise by default.
+    pub align: Option<HorizontalAlignment>,
+    /// `Units` for a fixed width and `Fill` or `FillPortion` to share the space left in a filling table,
+    /// the width of the content by default.
+    pub width: Option<Length>,
+    pub min_width: Option<f32>,
+    pub max_width: Option<f32>,
+    /// Shown when hovering the header, which shows the full label by default when it does not fit.
+    pub tooltip: Option<String>,
+    pub overflow: TextOverflow,
+}
+
+impl TableColumn {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Self::default()
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn short_name(mut self, short_name: impl Into<String>) -> Self {
+        self.short_name = Some(short_name.into());
+        self
+    }
+
+    pub fn kind(mut self, kind: CellKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Lets the cells of this column be edited with a double click.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    pub fn align(mut self, align: HorizontalAlignment) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = Some(min_width);
+        self
+    }
+
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    pub fn tooltip(mut self, tooltip: impl Into<String>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    /// Sets what cells show when their text is wider than the column, hovering a cut
+    /// text shows it in full.
+    pub fn overflow(mut self, overflow: TextOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub(crate) fn alignment(&self) -> HorizontalAlignment {
+        match (self.align, &self.kind) {
+            (Some(align), _) => align,
+            (None, CellKind::Number { .. }) => HorizontalAlignment::Right,
+            (None, _) => HorizontalAlignment::Left,
+        }
+    }
+
+    /// Keeps a width between the minimum and maximum of this column.
+    pub(crate) fn clamp_width(&self, width: f32) -> f32 {
+        let width = self.max_width.map_or(width, |max_width| width.min(max_width));
+        self.min_width.map_or(width, |min_width| width.max(min_width))
+    }
+
+    /// The share of the space left that this column fills.
+    pub(crate) fn fill_portion(&self) -> Option<u16> {
+        match self.width {
+            Some(Length::Fill) => Some(1),
+            Some(Length::FillPortion(portion)) => Some(portion),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for TableColumn {
@@ -14,12 +115,72 @@ impl std::fmt::Display for TableColumn {
     }
 }
 
-#[derive(Clone, PartialEq)]
+/// The widths, order and visibility of columns chosen by the user, which can be
+/// saved with serde and restored with `table::State::set_column_layout`.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ColumnLayout {
+    /// Column names in the order they are shown, columns missing from it follow in their given order.
+    pub order: Vec<String>,
+    /// Widths set by resizing, by column name.
+    pub widths: BTreeMap<String, f32>,
+    /// Names of the hidden columns.
+    pub hidden: Vec<String>,
+}
+
+impl ColumnLayout {
+    pub fn width(&self, column: &str) -> Option<f32> {
+        self.widths.get(column).copied()
+    }
+
+    pub fn set_width(&mut self, column: impl Into<String>, width: f32) {
+        self.widths.insert(column.into(), width);
+    }
+
+    /// Sizes the column to its content again.
+    pub fn reset_width(&mut self, column: &str) {
+        self.widths.remove(column);
+    }
+
+    pub fn is_hidden(&self, column: &str) -> bool {
+        self.hidden.iter().any(|name| name == column)
+    }
+
+    pub fn set_hidden(&mut self, column: impl Into<String>, is_hidden: bool) {
+        let column = column.into();
+        self.hidden.retain(|name| *name != column);
+        if is_hidden {
+            self.hidden.push(column);
+        }
+    }
+
+    /// Orders `columns` by this layout, returning the shown columns and the names of all of them.
+    pub(crate) fn arrange(&self, mut columns: Vec<TableColumn>) -> (Vec<TableColumn>, Vec<String>) {
+        columns.sort_by_key(|column| self.order.iter().position(|name| *name == column.name).unwrap_or(usize::MAX));
+        let order = columns.iter().map(|column| column.name.clone()).collect();
+        columns.retain(|column| !self.is_hidden(&column.name));
+        (columns, order)
+    }
+}
+
+/// What a cell shows when its text is wider than the column.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, SmartDefault)]
+pub enum TextOverflow {
+    /// Cuts the text at the border of the cell.
+    #[default]
+    Clip,
+    /// Shortens the text to end with `…`.
+    Ellipsis,
+    /// Breaks the text onto more lines, the rows grow to fit the tallest wrapped cell.
+    Wrap,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct TableOptions {
     pub orderable: bool,
 }
 
-#[derive(Copy, Clone, PartialEq, SmartDefault)]
+#[derive(Debug, Copy, Clone, PartialEq, SmartDefault)]
 pub enum TableOrder {
     #[default]
     Unordered = 0,
@@ -0,0 +1,46 @@
+use super::table;
+use iced_native::{layout::Node, overlay, Hasher, Layout, Point, Rectangle, Size};
+
+/// Shows a text below the bounds it belongs to, or above them when there is no room below.
+pub(crate) struct Tooltip<'a, Renderer: table::Renderer> {
+   text: &'a str,
+   anchor: Rectangle,
+   text_size: Option<u16>,
+   padding: u16,
+   font: Renderer::Font,
+   style: &'a <Renderer as table::Renderer>::Style,
+}
+
+impl<'a, Renderer: table::Renderer> Tooltip<'a, Renderer> {
+   pub fn new(text: &'a str, anchor: Rectangle, text_size: Option<u16>, padding: u16, font: Renderer::Font, style: &'a <Renderer as table::Renderer>::Style) -> Self {
+      Self { text, anchor, text_size, padding, font, style }
+   }
+}
+
+impl<'a, Message, Renderer: table::Renderer> overlay::Overlay<Message, Renderer> for Tooltip<'a, Renderer> {
+   fn layout(&self, renderer: &Renderer, bounds: Size, position: Point) -> Node {
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      let (width, height) = renderer.measure(self.text, text_size, self.font, Size::new(bounds.width, f32::INFINITY));
+      let size = Size::new(width, height).pad(f32::from(self.padding));
+      let y = if position.y + size.height > bounds.height { self.anchor.y - size.height } else { position.y };
+      let mut node = Node::new(size);
+      node.move_to(Point::new(position.x.min(bounds.width - size.width).max(0.0), y.max(0.0)));
+      node
+   }
+
+   fn draw(&self, renderer: &mut Renderer, _defaults: &Renderer::Defaults, layout: Layout<'_>, _cursor_position: Point) -> Renderer::Output {
+      let text_size = self.text_size.unwrap_or(renderer.default_size());
+      table::Renderer::draw_tooltip(renderer, layout.bounds(), self.text, text_size, self.padding, self.font, self.style)
+   }
+
+   fn hash_layout(&self, state: &mut Hasher, position: Point) {
+      use std::hash::Hash;
+      struct Marker;
+      std::any::TypeId::of::<Marker>().hash(state);
+
+      (position.x as u32).hash(state);
+      (position.y as u32).hash(state);
+      (self.anchor.y as u32).hash(state);
+      self.text.hash(state);
+   }
+}
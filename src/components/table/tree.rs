@@ -0,0 +1,96 @@
+use super::{
+   source::TableSource,
+   table::TableData,
+};
+use std::collections::{HashMap, HashSet};
+
+/// Records forming a tree, shown as such with `Table::tree`. Every record of the
+/// source is a node, its parent is found by key.
+pub trait TreeData: TableData {
+   /// Identifies the record among all nodes, the expanded nodes are remembered by it.
+   fn key(&self) -> String;
+
+   /// The key of the parent node, `None` for a root.
+   fn parent_key(&self) -> Option<String>;
+
+   /// Whether the node has children that are not loaded yet. The node can then be expanded,
+   /// and `Table::on_expand` is the time to load them.
+   fn has_children(&self) -> bool {
+      false
+   }
+}
+
+/// The functions of [`TreeData`], held by a table of any record type.
+pub(crate) struct TreeFns<T> {
+   pub key: fn(&T) -> String,
+   pub parent_key: fn(&T) -> Option<String>,
+   pub has_children: fn(&T) -> bool,
+}
+
+impl<T: TreeData> TreeFns<T> {
+   pub fn new() -> Self {
+      Self {
+         key: T::key,
+         parent_key: T::parent_key,
+         has_children: T::has_children,
+      }
+   }
+}
+
+/// Where a row stands in the tree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct TreeNode {
+   pub depth: usize,
+   pub is_expandable: bool,
+   pub is_expanded: bool,
+}
+
+/// Orders `rows` depth-first, each node followed by its children in the order they have in `rows`,
+/// and leaves out the children of collapsed nodes. The ancestors of the rows are kept, so that
+/// filtered records show where they are in the tree.
+pub(crate) fn flatten<T: TableData, S: TableSource<T> + ?Sized>(data: &S, rows: &[usize], tree: &TreeFns<T>, expanded: &HashSet<String>) -> (Vec<usize>, HashMap<usize, TreeNode>) {
+   let keys: HashMap<String, usize> = (0..data.len()).filter_map(|row| data.get(row).map(|record| ((tree.key)(record), row))).collect();
+   let parent = |row: usize| data.get(row).and_then(|record| (tree.parent_key)(record)).and_then(|key| keys.get(&key).copied()).filter(|&parent| parent != row);
+
+   let mut kept: Vec<usize> = rows.to_vec();
+   let mut is_kept: HashSet<usize> = rows.iter().copied().collect();
+   for &row in rows {
+      let mut ancestor = parent(row);
+      while let Some(row) = ancestor.filter(|&row| is_kept.insert(row)) {
+         kept.push(row);
+         ancestor = parent(row);
+      }
+   }
+
+   let mut children: HashMap<Option<usize>, Vec<usize>> = HashMap::new();
+   for &row in kept.iter() {
+      children.entry(parent(row)).or_default().push(row);
+   }
+
+   let mut shown = Vec::with_capacity(kept.len());
+   let mut nodes = HashMap::with_capacity(kept.len());
+   let mut visited = HashSet::new();
+   // depth-first, the stack holds the nodes still to show in reverse order
+   let mut stack: Vec<(usize, usize)> = children.get(&None).map_or_else(Vec::new, |roots| roots.iter().rev().map(|&row| (row, 0)).collect());
+   while let Some((row, depth)) = stack.pop() {
+      if !visited.insert(row) {
+         continue;
+      }
+      let record = match data.get(row) {
+         Some(record) => record,
+         None => continue,
+      };
+      let own_children = children.get(&Some(row));
+      let is_expanded = expanded.contains(&(tree.key)(record));
+      shown.push(row);
+      nodes.insert(row, TreeNode {
+         depth,
+         is_expandable: own_children.is_some() || (tree.has_children)(record),
+         is_expanded,
+      });
+      if let (true, Some(own_children)) = (is_expanded, own_children) {
+         stack.extend(own_children.iter().rev().map(|&child| (child, depth + 1)));
+      }
+   }
+   (shown, nodes)
+}
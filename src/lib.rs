@@ -2,3 +2,9 @@ pub mod utils;
 pub mod components;
 pub mod styles;
 pub use components::*;
+
+/// Used by the code generated by the derive macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+   pub use serde_json;
+}
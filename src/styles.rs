@@ -1,4 +1,5 @@
 pub mod card;
+pub mod grid;
 pub mod stepper;
 pub mod tab;
 pub mod table_row;
@@ -0,0 +1,61 @@
+use iced_native::{Background, Color};
+
+pub struct Style {
+   pub background: Option<Background>,
+   /// The background of the selected items.
+   pub selected_background: Background,
+   /// The border drawn around the highlighted item while the grid has focus.
+   pub highlight_border_color: Color,
+   pub highlight_border_width: f32,
+   /// The background left at the place a dragged item would be dropped at.
+   pub placeholder_background: Background,
+   /// The background drawn under the dragged item as it follows the cursor.
+   pub ghost_background: Background,
+   pub border_radius: f32,
+   /// The scroller of a virtualized grid, and the one hovered or dragged.
+   pub scroller_color: Color,
+   pub scroller_active_color: Color,
+}
+
+impl std::default::Default for Style {
+   fn default() -> Self {
+      Self {
+         background: None,
+         selected_background: Background::Color(Color::from_rgba8(30, 136, 229, 0.2)),
+         highlight_border_color: Color::from_rgb8(30, 136, 229),
+         highlight_border_width: 2.0,
+         placeholder_background: Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.06)),
+         ghost_background: Background::Color(Color::from_rgba(1.0, 1.0, 1.0, 0.8)),
+         border_radius: 4.0,
+         scroller_color: Color::from_rgba(0.0, 0.0, 0.0, 0.4),
+         scroller_active_color: Color::from_rgba(0.0, 0.0, 0.0, 0.6),
+      }
+   }
+}
+
+pub trait StyleSheet {
+   fn active(&self) -> Style;
+}
+
+pub struct Default;
+
+impl StyleSheet for Default {
+   fn active(&self) -> Style {
+      Style::default()
+   }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+   fn default() -> Self {
+      Box::new(Default)
+   }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+   T: 'static + StyleSheet,
+{
+   fn from(style: T) -> Self {
+      Box::new(style)
+   }
+}
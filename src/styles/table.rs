@@ -1,4 +1,5 @@
 use super::table_row;
+use iced_graphics::scrollable::{Scrollbar, Scroller};
 use iced_native::{Background, Color};
 
 pub struct Style {
@@ -9,6 +10,18 @@ pub struct Style {
    pub border_radius: f32,
    pub border_width: f32,
    pub border_color: Color,
+   /// The background of every other row, the others use the one of `table_row`.
+   pub striped_background: Option<Background>,
+   /// The color of the lines between rows.
+   pub row_divider: Option<Color>,
+   /// The color of the lines between columns.
+   pub column_divider: Option<Color>,
+   pub accent_color: Color,
+   pub error_color: Color,
+   pub tooltip_background: Background,
+   pub tooltip_text_color: Color,
+   /// The color of the placeholders shown for records still loading.
+   pub skeleton_color: Color,
    pub icon_size: f32,
 }
 
@@ -22,6 +35,14 @@ impl std::default::Default for Style {
          border_radius: 0.0,
          border_width: 0.0,
          border_color: Color::BLACK,
+         striped_background: None,
+         row_divider: None,
+         column_divider: None,
+         accent_color: Color::from_rgb8(30, 136, 229),
+         error_color: Color::from_rgb8(229, 57, 53),
+         tooltip_background: Background::Color(Color::from_rgb8(255, 255, 225)),
+         tooltip_text_color: Color::BLACK,
+         skeleton_color: Color::from_rgba(0.0, 0.0, 0.0, 0.08),
          icon_size: 0.7,
       }
    }
@@ -40,6 +61,43 @@ pub trait StyleSheet {
          ..active
       }
    }
+
+   /// The style of the rows, which highlights the selected and hovered ones.
+   fn table_row(&self) -> Box<dyn table_row::StyleSheet> {
+      std::default::Default::default()
+   }
+
+   fn scrollbar(&self) -> Scrollbar {
+      Scrollbar {
+         background: None,
+         border_radius: 5.0,
+         border_width: 0.0,
+         border_color: Color::TRANSPARENT,
+         scroller: Scroller {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.5),
+            border_radius: 5.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+         },
+      }
+   }
+
+   fn scrollbar_hovered(&self) -> Scrollbar {
+      let scrollbar = self.scrollbar();
+
+      Scrollbar {
+         background: Some(Background::Color(Color::from_rgba(0.0, 0.0, 0.0, 0.1))),
+         scroller: Scroller {
+            color: Color::from_rgba(0.0, 0.0, 0.0, 0.7),
+            ..scrollbar.scroller
+         },
+         ..scrollbar
+      }
+   }
+
+   fn scrollbar_dragging(&self) -> Scrollbar {
+      self.scrollbar_hovered()
+   }
 }
 
 pub struct Default;
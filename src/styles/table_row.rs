@@ -36,6 +36,21 @@ pub trait StyleSheet {
       }
    }
 
+   fn hovered(&self) -> Style {
+      let active = self.active();
+      let selected_color = match active.selected_color {
+         Some(selected_color) => selected_color,
+         None => active.text_color,
+      };
+      Style {
+         background: Some(Background::Color(Color {
+            a: selected_color.a * 0.1,
+            ..selected_color
+         })),
+         ..active
+      }
+   }
+
    fn selected(&self) -> Style {
       let active = self.active();
 
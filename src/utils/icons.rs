@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Icons {
     FileContract ,
     Scroll ,
@@ -932,6 +932,942 @@ pub enum Icons {
     Infinity,
 }
 
+impl Icons {
+    /// Every icon, in the order they are declared.
+    pub const ALL: [Icons; 930] = [
+        Icons::FileContract,
+        Icons::Scroll,
+        Icons::Unlink,
+        Icons::Tablet,
+        Icons::CloudWithAChanceOfMeatball,
+        Icons::Building,
+        Icons::Map,
+        Icons::Jedi,
+        Icons::Stamp,
+        Icons::AlternateFeather,
+        Icons::NeutralFace,
+        Icons::EyeSlash,
+        Icons::Peace,
+        Icons::CaretSquareUp,
+        Icons::Equals,
+        Icons::SwimmingPool,
+        Icons::DrumSteelpan,
+        Icons::Copy,
+        Icons::Rss,
+        Icons::Bars,
+        Icons::ListOl,
+        Icons::Chair,
+        Icons::DotCircle,
+        Icons::GrinningFaceWithBigEyes,
+        Icons::Dna,
+        Icons::PlaneArrival,
+        Icons::Save,
+        Icons::Binoculars,
+        Icons::Language,
+        Icons::Italic,
+        Icons::ArrowDown,
+        Icons::Kaaba,
+        Icons::Taxi,
+        Icons::FaceWithTearsOfJoy,
+        Icons::ArrowLeft,
+        Icons::StarHalf,
+        Icons::Restroom,
+        Icons::Leaf,
+        Icons::Subway,
+        Icons::CowboyHat,
+        Icons::GripVertical,
+        Icons::Plane,
+        Icons::Tablets,
+        Icons::Egg,
+        Icons::Stopwatch,
+        Icons::MousePointer,
+        Icons::SmilingFace,
+        Icons::GrinningFaceWithSmilingEyes,
+        Icons::MinusCircle,
+        Icons::AudioFile,
+        Icons::Divide,
+        Icons::Blind,
+        Icons::HotPepper,
+        Icons::VolleyballBall,
+        Icons::ChessKing,
+        Icons::AlternateHospital,
+        Icons::BatteryFull,
+        Icons::WindowMaximize,
+        Icons::LoudlyCryingFace,
+        Icons::StickyNote,
+        Icons::AlternateTransgender,
+        Icons::VolumeOff,
+        Icons::UserGraduate,
+        Icons::UserTie,
+        Icons::Snowplow,
+        Icons::AlternateShareSquare,
+        Icons::AlignRight,
+        Icons::ThermometerFull,
+        Icons::MedicalFile,
+        Icons::GlobeWithEuropeShown,
+        Icons::BoxOpen,
+        Icons::Swimmer,
+        Icons::DigitalTachograph,
+        Icons::AlternateExchange,
+        Icons::Minus,
+        Icons::Hashtag,
+        Icons::Ship,
+        Icons::SortAlphabeticalUp,
+        Icons::Bold,
+        Icons::Poop,
+        Icons::SpellCheck,
+        Icons::AngleDown,
+        Icons::Umbrella,
+        Icons::ChevronCircleRight,
+        Icons::Film,
+        Icons::UserFriends,
+        Icons::PrescriptionBottle,
+        Icons::AlternateMedicalFile,
+        Icons::AngryFace,
+        Icons::PrayingHands,
+        Icons::XRay,
+        Icons::AlternateCrop,
+        Icons::Cocktail,
+        Icons::WindowRestore,
+        Icons::LessThan,
+        Icons::MicrophoneSlash,
+        Icons::Brain,
+        Icons::Star,
+        Icons::At,
+        Icons::Battery14Full,
+        Icons::AddressCard,
+        Icons::Comment,
+        Icons::ObjectUngroup,
+        Icons::AlternateSortAlphabeticalUp,
+        Icons::Filter,
+        Icons::BabyCarriage,
+        Icons::WinkingFace,
+        Icons::FileInvoice,
+        Icons::WindowClose,
+        Icons::Wheelchair,
+        Icons::BalanceScale,
+        Icons::StreetView,
+        Icons::Thermometer12Full,
+        Icons::CommentsDollar,
+        Icons::Cogs,
+        Icons::PeopleCarry,
+        Icons::Hamsa,
+        Icons::AlternateStarHalf,
+        Icons::MarsDouble,
+        Icons::Percent,
+        Icons::Tape,
+        Icons::BezierCurve,
+        Icons::Fill,
+        Icons::Dharmachakra,
+        Icons::MarsStrokeHorizontal,
+        Icons::SmokingBan,
+        Icons::UmbrellaBeach,
+        Icons::Bed,
+        Icons::HandHoldingUsDollar,
+        Icons::TableTennis,
+        Icons::Helicopter,
+        Icons::HandHoldingHeart,
+        Icons::VideoFile,
+        Icons::Undo,
+        Icons::AlternateSortNumericDown,
+        Icons::HushedFace,
+        Icons::Envelope,
+        Icons::FaceWithTongue,
+        Icons::Poll,
+        Icons::CaretSquareDown,
+        Icons::UtensilSpoon,
+        Icons::PencilRuler,
+        Icons::Battery12Full,
+        Icons::ToggleOn,
+        Icons::Briefcase,
+        Icons::RssSquare,
+        Icons::Headset,
+        Icons::Train,
+        Icons::PauseCircle,
+        Icons::FolderOpen,
+        Icons::UserEdit,
+        Icons::Video,
+        Icons::Folder,
+        Icons::YinYang,
+        Icons::Hryvnia,
+        Icons::BatteryEmpty,
+        Icons::Flag,
+        Icons::MedicalBriefcase,
+        Icons::Calculator,
+        Icons::Voicemail,
+        Icons::QuoteLeft,
+        Icons::Gavel,
+        Icons::Hands,
+        Icons::HandPointingRight,
+        Icons::AlternateUnlock,
+        Icons::Toilet,
+        Icons::MinusSquare,
+        Icons::PizzaSlice,
+        Icons::GlobeWithAmericasShown,
+        Icons::UserSlash,
+        Icons::Archive,
+        Icons::Sleigh,
+        Icons::AlternateArrowCircleDown,
+        Icons::Trash,
+        Icons::UserCog,
+        Icons::SortAmountDown,
+        Icons::HospitalSymbol,
+        Icons::ExclamationTriangle,
+        Icons::Allergies,
+        Icons::HourglassEnd,
+        Icons::ExcelFile,
+        Icons::Genderless,
+        Icons::SquareFull,
+        Icons::Landmark,
+        Icons::ShoppingCartArrowDown,
+        Icons::RulerHorizontal,
+        Icons::CaretSquareLeft,
+        Icons::PointerHand,
+        Icons::Tasks,
+        Icons::Skull,
+        Icons::Robot,
+        Icons::Snowflake,
+        Icons::FileExport,
+        Icons::Outdent,
+        Icons::Tags,
+        Icons::StarStruck,
+        Icons::VenusDouble,
+        Icons::Hotel,
+        Icons::Camera,
+        Icons::Palette,
+        Icons::StopCircle,
+        Icons::GripLines,
+        Icons::Comments,
+        Icons::Tty,
+        Icons::Vihara,
+        Icons::UserNinja,
+        Icons::Procedures,
+        Icons::Cookie,
+        Icons::Slash,
+        Icons::AlternateSortAmountUp,
+        Icons::ChevronRight,
+        Icons::ClosedCaptioning,
+        Icons::ThumbsDown,
+        Icons::Bacon,
+        Icons::AlternateSignIn,
+        Icons::BowlingBall,
+        Icons::AngleDoubleLeft,
+        Icons::Horse,
+        Icons::TruckLoading,
+        Icons::Strikethrough,
+        Icons::StarAndCrescent,
+        Icons::HotDog,
+        Icons::AssistiveListeningSystems,
+        Icons::Male,
+        Icons::Forward,
+        Icons::Check,
+        Icons::Route,
+        Icons::ArrowRight,
+        Icons::Water,
+        Icons::Images,
+        Icons::Khanda,
+        Icons::Crutch,
+        Icons::LayerGroup,
+        Icons::WonSign,
+        Icons::Compass,
+        Icons::TurkishLiraSign,
+        Icons::Crosshairs,
+        Icons::CodeFile,
+        Icons::FillDrip,
+        Icons::ThermometerEmpty,
+        Icons::CalendarWithDayFocus,
+        Icons::Medal,
+        Icons::Tractor,
+        Icons::ArrowUp,
+        Icons::TrashRestore,
+        Icons::DiceD20,
+        Icons::BirthdayCake,
+        Icons::Asterisk,
+        Icons::Poo,
+        Icons::AlternateRadiation,
+        Icons::BorderAll,
+        Icons::ChalkboardTeacher,
+        Icons::CloudWithRain,
+        Icons::Socks,
+        Icons::HelpingHands,
+        Icons::Capsules,
+        Icons::Heartbeat,
+        Icons::FileCsv,
+        Icons::PuzzlePiece,
+        Icons::CarBattery,
+        Icons::Atom,
+        Icons::SortNumericDown,
+        Icons::MapMarked,
+        Icons::SquintingFaceWithTongue,
+        Icons::Quran,
+        Icons::Burn,
+        Icons::StepForward,
+        Icons::Clipboard,
+        Icons::ChessRook,
+        Icons::Sitemap,
+        Icons::Book,
+        Icons::Igloo,
+        Icons::AngleRight,
+        Icons::TruckMoving,
+        Icons::Newspaper,
+        Icons::AlternatePrescriptionBottle,
+        Icons::HandPointingLeft,
+        Icons::HorizontalEllipsis,
+        Icons::EnvelopeOpenText,
+        Icons::ObjectGroup,
+        Icons::Image,
+        Icons::Keyboard,
+        Icons::ChessQueen,
+        Icons::Percentage,
+        Icons::Qrcode,
+        Icons::GrinningSquintingFace,
+        Icons::Baby,
+        Icons::Tint,
+        Icons::HandPointingDown,
+        Icons::FireExtinguisher,
+        Icons::StarOfDavid,
+        Icons::BreadSlice,
+        Icons::DollarSign,
+        Icons::AlternateCloudDownload,
+        Icons::BorderNone,
+        Icons::Gem,
+        Icons::CalendarWithWeekFocus,
+        Icons::KissingFaceWithSmilingEyes,
+        Icons::CaretUp,
+        Icons::WineGlass,
+        Icons::ChevronDown,
+        Icons::MoneyCheck,
+        Icons::SolarPanel,
+        Icons::DiceSix,
+        Icons::PhoneSquare,
+        Icons::AlternateMobile,
+        Icons::BusinessTime,
+        Icons::TextWidth,
+        Icons::AlternateExpandArrows,
+        Icons::UserLock,
+        Icons::School,
+        Icons::ShoePrints,
+        Icons::Quidditch,
+        Icons::Archway,
+        Icons::Info,
+        Icons::MoneyBill,
+        Icons::CommentSlash,
+        Icons::Braille,
+        Icons::AlternatePhoneSquare,
+        Icons::Cubes,
+        Icons::BalanceScaleRightWeighted,
+        Icons::Mitten,
+        Icons::Cube,
+        Icons::History,
+        Icons::GlobeWithAfricaShown,
+        Icons::AddressBook,
+        Icons::FaceWithoutMouth,
+        Icons::UserSecret,
+        Icons::PaperPlane,
+        Icons::GasPump,
+        Icons::Trophy,
+        Icons::AlternateArrowCircleUp,
+        Icons::PlayCircle,
+        Icons::Donate,
+        Icons::Fax,
+        Icons::Meteor,
+        Icons::UserTag,
+        Icons::University,
+        Icons::GreaterThan,
+        Icons::LineChart,
+        Icons::DiceFour,
+        Icons::Seedling,
+        Icons::Fish,
+        Icons::MedicalBook,
+        Icons::Mask,
+        Icons::MapSigns,
+        Icons::ExclamationCircle,
+        Icons::Tools,
+        Icons::ImageFile,
+        Icons::ChevronCircleDown,
+        Icons::Paragraph,
+        Icons::LaptopCode,
+        Icons::DizzyFace,
+        Icons::PiggyBank,
+        Icons::LaughingWinkingFace,
+        Icons::Skating,
+        Icons::Terminal,
+        Icons::BookOpen,
+        Icons::Ankh,
+        Icons::UserMinus,
+        Icons::Sort,
+        Icons::AlternateMoneyBill,
+        Icons::MartiniGlass,
+        Icons::CloudWithSunAndRain,
+        Icons::TextHeight,
+        Icons::CalendarTimes,
+        Icons::PenSquare,
+        Icons::Award,
+        Icons::UserPlus,
+        Icons::CloudWithSun,
+        Icons::Coins,
+        Icons::AlternateTachometer,
+        Icons::SkullCrossbones,
+        Icons::BorderStyle,
+        Icons::AlternateLevelDown,
+        Icons::Barcode,
+        Icons::ArrowCircleRight,
+        Icons::Tag,
+        Icons::AlternateArrowsHorizontal,
+        Icons::ClipboardWithCheck,
+        Icons::RollingOnTheFloorLaughing,
+        Icons::Sms,
+        Icons::RecordVinyl,
+        Icons::City,
+        Icons::AngleLeft,
+        Icons::Trademark,
+        Icons::TimesCircle,
+        Icons::Mountain,
+        Icons::ShoppingCart,
+        Icons::MarsStrokeVertical,
+        Icons::AlternateUserSlash,
+        Icons::GripHorizontal,
+        Icons::Ad,
+        Icons::Heading,
+        Icons::Walking,
+        Icons::Search,
+        Icons::DoubleCheck,
+        Icons::SearchDollar,
+        Icons::Box,
+        Icons::VoteYea,
+        Icons::SuitcaseRolling,
+        Icons::FolderMinus,
+        Icons::CalendarPlus,
+        Icons::Bug,
+        Icons::Democrat,
+        Icons::MapPin,
+        Icons::CryingFace,
+        Icons::FileUpload,
+        Icons::Bus,
+        Icons::GraduationCap,
+        Icons::FastBackward,
+        Icons::SortAmountUp,
+        Icons::ListUl,
+        Icons::Om,
+        Icons::Shower,
+        Icons::Icons,
+        Icons::AlternateUser,
+        Icons::Music,
+        Icons::Database,
+        Icons::Cut,
+        Icons::AlternateSortNumericUp,
+        Icons::AlternateRedo,
+        Icons::DoorClosed,
+        Icons::Reply,
+        Icons::Thermometer14Full,
+        Icons::ArrowCircleLeft,
+        Icons::Haykal,
+        Icons::Home,
+        Icons::ChessBoard,
+        Icons::CaretLeft,
+        Icons::InfoCircle,
+        Icons::Warehouse,
+        Icons::FruitApple,
+        Icons::Motorcycle,
+        Icons::PowerpointFile,
+        Icons::ChargingStation,
+        Icons::HockeyPuck,
+        Icons::ShoppingBasket,
+        Icons::Lock,
+        Icons::AlignCenter,
+        Icons::NotEqual,
+        Icons::Hdd,
+        Icons::Exclamation,
+        Icons::Chess,
+        Icons::PoundSign,
+        Icons::Hourglass,
+        Icons::AlternateFire,
+        Icons::Laptop,
+        Icons::Ring,
+        Icons::DiceFive,
+        Icons::DiceOne,
+        Icons::Cannabis,
+        Icons::CreditCard,
+        Icons::Tooth,
+        Icons::UserShield,
+        Icons::Subscript,
+        Icons::BookReader,
+        Icons::Pager,
+        Icons::Industry,
+        Icons::GlobeWithAsiaShown,
+        Icons::DumpsterFire,
+        Icons::RulerVertical,
+        Icons::Underline,
+        Icons::Wind,
+        Icons::BellSlash,
+        Icons::Lemon,
+        Icons::PhotoVideo,
+        Icons::QuoteRight,
+        Icons::AlternateStore,
+        Icons::ReplyAll,
+        Icons::AlternateExternalLinkSquare,
+        Icons::AlignLeft,
+        Icons::Snowboarding,
+        Icons::PenFancy,
+        Icons::AlternateArrowsVertical,
+        Icons::Th,
+        Icons::AlternateSortAmountDown,
+        Icons::PooStorm,
+        Icons::Pause,
+        Icons::CowboyHatSide,
+        Icons::PlaceOfWorship,
+        Icons::Gopuram,
+        Icons::Ethernet,
+        Icons::FighterJet,
+        Icons::AlternateExternalLink,
+        Icons::Snowman,
+        Icons::DollyFlatbed,
+        Icons::AlternateGrinningFace,
+        Icons::WizardSHat,
+        Icons::PenNib,
+        Icons::Dove,
+        Icons::SearchLocation,
+        Icons::Cat,
+        Icons::ArrowCircleDown,
+        Icons::AlternateArrowCircleRight,
+        Icons::EuroSign,
+        Icons::Icicles,
+        Icons::Random,
+        Icons::VenusMars,
+        Icons::VerticalEllipsis,
+        Icons::ArchiveFile,
+        Icons::AlternateTicket,
+        Icons::AlternateCar,
+        Icons::AlternateLongArrowLeft,
+        Icons::LizardHand,
+        Icons::Magnet,
+        Icons::AlternateLongArrowUp,
+        Icons::Crow,
+        Icons::Marker,
+        Icons::YenSign,
+        Icons::BaseballBall,
+        Icons::BalanceScaleLeftWeighted,
+        Icons::Pray,
+        Icons::Redo,
+        Icons::Eraser,
+        Icons::Utensils,
+        Icons::VideoSlash,
+        Icons::Eject,
+        Icons::Thermometer34Full,
+        Icons::Thumbtack,
+        Icons::StarOfLife,
+        Icons::BookOfTheDead,
+        Icons::CashRegister,
+        Icons::Fire,
+        Icons::Ghost,
+        Icons::ThumbsUp,
+        Icons::HorseHead,
+        Icons::Anchor,
+        Icons::Smog,
+        Icons::AlternateCloudUpload,
+        Icons::Hospital,
+        Icons::ThLarge,
+        Icons::VolumeUp,
+        Icons::RubleSign,
+        Icons::KissingFace,
+        Icons::FaceBlowingAKiss,
+        Icons::BarChart,
+        Icons::Boxes,
+        Icons::Play,
+        Icons::Pallet,
+        Icons::AlternateShield,
+        Icons::Wrench,
+        Icons::Ribbon,
+        Icons::CommentDollar,
+        Icons::Rainbow,
+        Icons::DiceD6,
+        Icons::Link,
+        Icons::Deaf,
+        Icons::Globe,
+        Icons::Torah,
+        Icons::SkiingNordic,
+        Icons::HeartBroken,
+        Icons::Dungeon,
+        Icons::Heart,
+        Icons::MortarPestle,
+        Icons::Shapes,
+        Icons::ChevronUp,
+        Icons::FootballBall,
+        Icons::Guitar,
+        Icons::TrafficLight,
+        Icons::AlignJustify,
+        Icons::Fingerprint,
+        Icons::Dog,
+        Icons::AlternateComment,
+        Icons::Users,
+        Icons::Drum,
+        Icons::PollH,
+        Icons::PlusSquare,
+        Icons::AlternateHeadphones,
+        Icons::AlternateList,
+        Icons::Microchip,
+        Icons::SmilingFaceWithHeartEyes,
+        Icons::Paw,
+        Icons::AlternateArrowCircleLeft,
+        Icons::TruckMonster,
+        Icons::Hamburger,
+        Icons::Radiation,
+        Icons::Mars,
+        Icons::AngleDoubleRight,
+        Icons::ConciergeBell,
+        Icons::Satellite,
+        Icons::SearchPlus,
+        Icons::Stethoscope,
+        Icons::Table,
+        Icons::Rocket,
+        Icons::TiredFace,
+        Icons::PeaceHand,
+        Icons::Times,
+        Icons::Bible,
+        Icons::Unlock,
+        Icons::Swatchbook,
+        Icons::Carrot,
+        Icons::AlternateSignOut,
+        Icons::HighTemperature,
+        Icons::Biking,
+        Icons::StepBackward,
+        Icons::Upload,
+        Icons::Syringe,
+        Icons::AlternateShare,
+        Icons::Otter,
+        Icons::CalendarMinus,
+        Icons::Pills,
+        Icons::GlassWhiskey,
+        Icons::Lightbulb,
+        Icons::AlternateArrows,
+        Icons::Key,
+        Icons::CandyCane,
+        Icons::Adjust,
+        Icons::CloudWithHeavyShowers,
+        Icons::WavyMoneyBill,
+        Icons::Glasses,
+        Icons::Memory,
+        Icons::Download,
+        Icons::Car,
+        Icons::Synagogue,
+        Icons::GripLinesVertical,
+        Icons::HollyBerry,
+        Icons::MailBulk,
+        Icons::Diagnoses,
+        Icons::CheckSquare,
+        Icons::Running,
+        Icons::Blog,
+        Icons::User,
+        Icons::Retweet,
+        Icons::CodeBranch,
+        Icons::SpockHand,
+        Icons::Screwdriver,
+        Icons::Dragon,
+        Icons::ToiletPaper,
+        Icons::Wifi,
+        Icons::ThList,
+        Icons::FunnelDollar,
+        Icons::MarsStroke,
+        Icons::Joint,
+        Icons::AngleUp,
+        Icons::AreaChart,
+        Icons::LowTemperature,
+        Icons::PieChart,
+        Icons::FolderPlus,
+        Icons::Ban,
+        Icons::Menorah,
+        Icons::AlternateLongArrowDown,
+        Icons::HardHat,
+        Icons::CarSide,
+        Icons::Female,
+        Icons::BlenderPhone,
+        Icons::Cloud,
+        Icons::FileDownload,
+        Icons::Splotch,
+        Icons::Tenge,
+        Icons::Server,
+        Icons::HotTub,
+        Icons::LowVision,
+        Icons::AlternateMedicalChat,
+        Icons::Desktop,
+        Icons::UserAstronaut,
+        Icons::IdentificationCard,
+        Icons::Paperclip,
+        Icons::Sync,
+        Icons::PaintBrush,
+        Icons::Gifts,
+        Icons::Mercury,
+        Icons::CalendarCheck,
+        Icons::BandAid,
+        Icons::Moon,
+        Icons::Ruler,
+        Icons::Feather,
+        Icons::Microscope,
+        Icons::AirFreshener,
+        Icons::ChessPawn,
+        Icons::ShuttleVan,
+        Icons::Calendar,
+        Icons::File,
+        Icons::SimCard,
+        Icons::ArrowCircleUp,
+        Icons::Venus,
+        Icons::Gamepad,
+        Icons::ParachuteBox,
+        Icons::Suitcase,
+        Icons::PaintRoller,
+        Icons::MugHot,
+        Icons::Indent,
+        Icons::PhoneVolume,
+        Icons::AlternateCompressArrows,
+        Icons::Clock,
+        Icons::Magic,
+        Icons::Spinner,
+        Icons::ShekelSign,
+        Icons::Sun,
+        Icons::Share,
+        Icons::AmericanSignLanguageInterpreting,
+        Icons::Cross,
+        Icons::SortAlphabeticalDown,
+        Icons::HandPointingUp,
+        Icons::Flask,
+        Icons::UserCircle,
+        Icons::Vials,
+        Icons::JournalOfTheWhills,
+        Icons::Plus,
+        Icons::AlternateMapMarker,
+        Icons::Bong,
+        Icons::SprayCan,
+        Icons::EnvelopeOpen,
+        Icons::Store,
+        Icons::CompactDisc,
+        Icons::UniversalAccess,
+        Icons::Print,
+        Icons::Wallet,
+        Icons::Compress,
+        Icons::HandWithMiddleFingerRaised,
+        Icons::AlternateUndo,
+        Icons::Question,
+        Icons::AlternativeTrashRestore,
+        Icons::IndianRupeeSign,
+        Icons::AlternateSortAlphabeticalDown,
+        Icons::Prescription,
+        Icons::CaretRight,
+        Icons::Road,
+        Icons::AlternatePencil,
+        Icons::GlassCheers,
+        Icons::Vial,
+        Icons::AlternateMicrophone,
+        Icons::AlternateMapMarked,
+        Icons::Nurse,
+        Icons::Pen,
+        Icons::Edit,
+        Icons::CaretDown,
+        Icons::ChessKnight,
+        Icons::AlternatePhone,
+        Icons::BasketballBall,
+        Icons::FilePrescription,
+        Icons::CardboardVr,
+        Icons::Circle,
+        Icons::HorizontalSliders,
+        Icons::AlternateMoneyCheck,
+        Icons::Tram,
+        Icons::CloudWithMoonAndRain,
+        Icons::WordFile,
+        Icons::Bullseye,
+        Icons::Tree,
+        Icons::LessThanEqualTo,
+        Icons::Chalkboard,
+        Icons::ToggleOff,
+        Icons::Signal,
+        Icons::CircleNotched,
+        Icons::Hippo,
+        Icons::EnvelopeSquare,
+        Icons::FileSignature,
+        Icons::AlternateFile,
+        Icons::DiceThree,
+        Icons::FlushedFace,
+        Icons::Dumbbell,
+        Icons::Transgender,
+        Icons::LifeRing,
+        Icons::SquareWave,
+        Icons::AlternateWavyMoneyBill,
+        Icons::AudioDescription,
+        Icons::Frog,
+        Icons::CaretSquareRight,
+        Icons::RemoveFormat,
+        Icons::Bicycle,
+        Icons::PowerOff,
+        Icons::TeethOpen,
+        Icons::PlaneDeparture,
+        Icons::Inbox,
+        Icons::Parking,
+        Icons::PersonEnteringBooth,
+        Icons::RulerCombined,
+        Icons::WineBottle,
+        Icons::Backspace,
+        Icons::HourglassStart,
+        Icons::BeamingFaceWithSmilingEyes,
+        Icons::Pastafarianism,
+        Icons::Crop,
+        Icons::DrumstickWithBiteTakenOut,
+        Icons::Republican,
+        Icons::Bomb,
+        Icons::WiredNetwork,
+        Icons::Crown,
+        Icons::UsersCog,
+        Icons::QuestionCircle,
+        Icons::RemoveUser,
+        Icons::TintSlash,
+        Icons::Eye,
+        Icons::Hammer,
+        Icons::PdfFile,
+        Icons::Signature,
+        Icons::FrowningFace,
+        Icons::FlagCheckered,
+        Icons::UserCheck,
+        Icons::Bath,
+        Icons::WindowMinimize,
+        Icons::Podcast,
+        Icons::Microphone,
+        Icons::TheaterMasks,
+        Icons::Spa,
+        Icons::UserInjured,
+        Icons::RegisteredTrademark,
+        Icons::Blender,
+        Icons::SatelliteDish,
+        Icons::CommentDots,
+        Icons::WinkingFaceWithTongue,
+        Icons::Cog,
+        Icons::Futbol,
+        Icons::SearchMinus,
+        Icons::DamagedHouse,
+        Icons::IceCream,
+        Icons::RetroCamera,
+        Icons::ChevronCircleLeft,
+        Icons::EyeDropper,
+        Icons::TShirt,
+        Icons::DrawPolygon,
+        Icons::Church,
+        Icons::Dice,
+        Icons::Thermometer,
+        Icons::Bone,
+        Icons::AlternateMicrophoneSlash,
+        Icons::MedicalNotes,
+        Icons::Clone,
+        Icons::ShareSquare,
+        Icons::IBeamCursor,
+        Icons::LaptopMedical,
+        Icons::HourglassHalf,
+        Icons::Broom,
+        Icons::ChessBishop,
+        Icons::SdCard,
+        Icons::GreaterThanEqualTo,
+        Icons::Brush,
+        Icons::Gift,
+        Icons::Toolbox,
+        Icons::Code,
+        Icons::Mouse,
+        Icons::VectorSquare,
+        Icons::AddToShoppingCart,
+        Icons::PhoneSlash,
+        Icons::ShippingFast,
+        Icons::BroadcastTower,
+        Icons::Copyright,
+        Icons::Plug,
+        Icons::SpaceShuttle,
+        Icons::Bell,
+        Icons::Teeth,
+        Icons::Mosque,
+        Icons::Monument,
+        Icons::AlternatePen,
+        Icons::Doctor,
+        Icons::SortNumericUp,
+        Icons::AngleDoubleDown,
+        Icons::FileImport,
+        Icons::Stroopwafel,
+        Icons::PaperHand,
+        Icons::DoorOpen,
+        Icons::Dumpster,
+        Icons::PlusCircle,
+        Icons::ScissorsHand,
+        Icons::FileInvoiceWithUsDollar,
+        Icons::LightningBolt,
+        Icons::Bookmark,
+        Icons::Atlas,
+        Icons::Stop,
+        Icons::AlternateWineGlas,
+        Icons::Battery34Full,
+        Icons::GrimacingFace,
+        Icons::HandHolding,
+        Icons::Weight,
+        Icons::AlternateLevelUp,
+        Icons::Television,
+        Icons::Portrait,
+        Icons::ClipboardList,
+        Icons::Campground,
+        Icons::Ambulance,
+        Icons::Passport,
+        Icons::Fan,
+        Icons::CheckCircle,
+        Icons::LocationArrow,
+        Icons::AngleDoubleUp,
+        Icons::Dolly,
+        Icons::GolfBall,
+        Icons::DraftingCompass,
+        Icons::Handshake,
+        Icons::KiwiBird,
+        Icons::ShoppingBag,
+        Icons::Font,
+        Icons::Bullhorn,
+        Icons::Sign,
+        Icons::ProjectDiagram,
+        Icons::ChevronLeft,
+        Icons::Directions,
+        Icons::AlternateSquareRoot,
+        Icons::SignLanguage,
+        Icons::BusAlt,
+        Icons::Child,
+        Icons::Couch,
+        Icons::CarCrash,
+        Icons::DiceTwo,
+        Icons::Receipt,
+        Icons::ChevronCircleUp,
+        Icons::Phone,
+        Icons::Recycle,
+        Icons::Hiking,
+        Icons::GrinningFaceWithSweat,
+        Icons::Spider,
+        Icons::Beer,
+        Icons::IdentificationBadge,
+        Icons::Biohazard,
+        Icons::VolumeDown,
+        Icons::UnitedStatesOfAmericaFlag,
+        Icons::Cheese,
+        Icons::Columns,
+        Icons::AlternateIdentificationCard,
+        Icons::TruckSide,
+        Icons::RaisedFist,
+        Icons::Hanukiah,
+        Icons::Smoking,
+        Icons::RockHand,
+        Icons::HangingWeight,
+        Icons::CookieBite,
+        Icons::Stream,
+        Icons::Skiing,
+        Icons::ToriiGate,
+        Icons::FaceWithRollingEyes,
+        Icons::Coffee,
+        Icons::AlternateLongArrowRight,
+        Icons::UserClock,
+        Icons::OilCan,
+        Icons::CloudWithMoon,
+        Icons::Superscript,
+        Icons::AlternateTrash,
+        Icons::Expand,
+        Icons::Infinity,
+    ];
+}
+
 
 fn icon_unicode(icon: Icons) -> char {
     use self::Icons::*;